@@ -1,28 +1,130 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, BytesN, Env, String, Symbol, Vec,
+};
 use stellar_default_impl_macro::default_impl;
-use stellar_non_fungible::{Balance, Base, NonFungibleToken, TokenId};
+use stellar_non_fungible::{allowlist, capped, Balance, Base, NonFungibleToken, TokenId};
+
+mod sale;
+
+pub use sale::{Phase, PhaseKind, SaleError};
+
+/// Storage key for the address allowed to mint.
+pub const ADMIN: Symbol = symbol_short!("ADMIN");
 
 #[contract]
 pub struct OZStellarNFT;
 
 #[contractimpl]
 impl OZStellarNFT {
-    pub fn __constructor(e: &Env) {
+    pub fn __constructor(e: &Env, admin: Address, max_supply: TokenId) {
         Base::set_metadata(
             e,
             String::from_str(e, "ipfs://bafkreigjf3tymofuq5vepmlijsglf65qprsiwykkkz6ipdgxv6fnxcje4e"),
             String::from_str(e, "SoroKittens"),
             String::from_str(e, "SKT"),
         );
+        e.storage().instance().set(&ADMIN, &admin);
+        capped::set_cap(e, max_supply);
+    }
+
+    pub fn admin(e: &Env) -> Address {
+        e.storage().instance().get(&ADMIN).expect("admin should be set")
+    }
+
+    pub fn max_supply(e: &Env) -> TokenId {
+        capped::query_cap(e)
     }
 
     pub fn mint(e: &Env, to: Address) -> TokenId {
-        let token_id: TokenId = Base::sequential_mint(e, &to);
-        if token_id > 100 {
-            panic!("Maximum minted already");
-        }
-        token_id
+        Self::admin(e).require_auth();
+
+        // `sequential_mint` checks the cap before touching any other storage.
+        Base::sequential_mint(e, &to)
+    }
+
+    pub fn add_phase(e: &Env, phase: Phase) -> u32 {
+        Self::admin(e).require_auth();
+        sale::add_phase(e, &phase)
+    }
+
+    pub fn update_phase(e: &Env, phase_id: u32, phase: Phase) {
+        Self::admin(e).require_auth();
+        sale::update_phase(e, phase_id, &phase);
+    }
+
+    pub fn phase(e: &Env, phase_id: u32) -> Phase {
+        sale::get_phase(e, phase_id)
+    }
+
+    pub fn phase_count(e: &Env) -> u32 {
+        sale::phase_count(e)
+    }
+
+    pub fn phase_minted(e: &Env, phase_id: u32) -> Balance {
+        sale::phase_minted(e, phase_id)
+    }
+
+    pub fn wallet_minted(e: &Env, phase_id: u32, wallet: Address) -> Balance {
+        sale::wallet_minted(e, phase_id, &wallet)
+    }
+
+    pub fn set_allowlist_root(e: &Env, root: BytesN<32>) {
+        Self::admin(e).require_auth();
+        allowlist::set_allowlist_root(e, &root);
+    }
+
+    /// Hides the metadata behind `uri` until `reveal` is called.
+    pub fn set_placeholder_uri(e: &Env, uri: String) {
+        Self::admin(e).require_auth();
+        Base::set_placeholder_uri(e, uri);
+    }
+
+    /// Commits to the base URI the collection will be revealed with, as
+    /// `sha256(base_uri)`.
+    pub fn set_provenance_hash(e: &Env, hash: BytesN<32>) {
+        Self::admin(e).require_auth();
+        Base::set_provenance_hash(e, &hash);
+    }
+
+    /// Reveals the collection with `base_uri`, shuffling the token ids over
+    /// the whole supply with a random offset.
+    pub fn reveal(e: &Env, base_uri: String) {
+        Self::admin(e).require_auth();
+        Base::reveal(e, base_uri, capped::query_cap(e));
+    }
+
+    /// Mints a token to `buyer` during the public phase `phase_id`, for the
+    /// price of the phase.
+    pub fn public_mint(e: &Env, buyer: Address, phase_id: u32) -> TokenId {
+        buyer.require_auth();
+        sale::purchase(e, phase_id, PhaseKind::Public, &buyer);
+
+        Base::sequential_mint(e, &buyer)
+    }
+
+    /// Mints a token to `buyer` during the allowlist phase `phase_id`, for
+    /// the price of the phase. `allowance` and `proof` must match the leaf of
+    /// `buyer` in the allowlist, and `allowance` is shared by all the
+    /// allowlist phases.
+    pub fn allowlist_mint(
+        e: &Env,
+        buyer: Address,
+        phase_id: u32,
+        allowance: Balance,
+        proof: Vec<BytesN<32>>,
+    ) -> TokenId {
+        buyer.require_auth();
+        sale::purchase(e, phase_id, PhaseKind::Allowlist, &buyer);
+
+        allowlist::allowlist_mint(e, &buyer, allowance, &proof)
+    }
+
+    /// Transfers the proceeds paid in `token` to `to`, and returns the
+    /// transferred amount.
+    pub fn withdraw(e: &Env, token: Address, to: Address) -> i128 {
+        Self::admin(e).require_auth();
+        sale::withdraw(e, &token, &to)
     }
 }
 
@@ -31,3 +133,5 @@ impl OZStellarNFT {
 impl NonFungibleToken for OZStellarNFT {
     type ContractType = Base;
 }
+
+mod test;
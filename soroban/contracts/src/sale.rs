@@ -0,0 +1,192 @@
+//! Sale phases of the collection.
+//!
+//! A sale is split into phases, each open between a start and an end ledger
+//! (both inclusive), with its own price, payment token and limits. A phase is
+//! either open to everyone, or restricted to the accounts of the allowlist
+//! (see [`stellar_non_fungible::allowlist`]).
+//!
+//! The proceeds stay in the contract until the admin withdraws them.
+
+use soroban_sdk::{contracterror, contracttype, panic_with_error, token, Address, Env};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+use stellar_non_fungible::Balance;
+
+/// Who can mint during a phase.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PhaseKind {
+    /// Only the allowlisted accounts, up to their allowance.
+    Allowlist,
+    /// Everyone.
+    Public,
+}
+
+/// Configuration of a sale phase.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Phase {
+    pub kind: PhaseKind,
+    /// First ledger of the phase.
+    pub start_ledger: u32,
+    /// Last ledger of the phase.
+    pub end_ledger: u32,
+    /// Price of a token, in units of `payment_token`.
+    pub price: i128,
+    /// SEP-41 token the price is paid with.
+    pub payment_token: Address,
+    /// Maximum number of tokens a single wallet can mint during the phase.
+    pub wallet_limit: Balance,
+    /// Maximum number of tokens minted during the phase.
+    pub phase_limit: Balance,
+}
+
+/// Storage keys for the data associated with the sale phases
+#[contracttype]
+pub enum SaleStorageKey {
+    Phase(u32),
+    PhaseCount,
+    PhaseMinted(u32),
+    WalletMinted(u32, Address),
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SaleError {
+    /// The phase ends before it starts, or has a negative price.
+    InvalidPhase = 1,
+    /// The phase does not exist.
+    PhaseNotFound = 2,
+    /// The current ledger is outside of the phase.
+    PhaseNotActive = 3,
+    /// The phase is not of the kind required by the mint function.
+    WrongPhaseKind = 4,
+    /// The wallet already minted its limit during the phase.
+    WalletLimitExceeded = 5,
+    /// The phase already minted its limit.
+    PhaseLimitExceeded = 6,
+}
+
+/// Returns the number of phases.
+pub fn phase_count(e: &Env) -> u32 {
+    e.storage().instance().get(&SaleStorageKey::PhaseCount).unwrap_or(0)
+}
+
+/// Returns the phase `id`.
+///
+/// # Errors
+///
+/// * [`SaleError::PhaseNotFound`] - When the phase does not exist.
+pub fn get_phase(e: &Env, id: u32) -> Phase {
+    e.storage()
+        .instance()
+        .get(&SaleStorageKey::Phase(id))
+        .unwrap_or_else(|| panic_with_error!(e, SaleError::PhaseNotFound))
+}
+
+/// Returns the number of tokens minted during the phase `id`.
+pub fn phase_minted(e: &Env, id: u32) -> Balance {
+    e.storage().instance().get(&SaleStorageKey::PhaseMinted(id)).unwrap_or(0)
+}
+
+/// Returns the number of tokens minted by `wallet` during the phase `id`.
+pub fn wallet_minted(e: &Env, id: u32, wallet: &Address) -> Balance {
+    let key = SaleStorageKey::WalletMinted(id, wallet.clone());
+    if let Some(minted) = e.storage().persistent().get::<_, Balance>(&key) {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        minted
+    } else {
+        0
+    }
+}
+
+/// Adds a new phase and returns its id. Ids start at `0`.
+///
+/// # Errors
+///
+/// * [`SaleError::InvalidPhase`] - When the phase is not valid.
+pub fn add_phase(e: &Env, phase: &Phase) -> u32 {
+    let id = phase_count(e);
+    set_phase(e, id, phase);
+    e.storage().instance().set(&SaleStorageKey::PhaseCount, &(id + 1));
+    id
+}
+
+/// Replaces the configuration of the phase `id`. The tokens already minted
+/// during the phase are still counted against the new limits.
+///
+/// # Errors
+///
+/// * [`SaleError::InvalidPhase`] - When the phase is not valid.
+pub fn update_phase(e: &Env, id: u32, phase: &Phase) {
+    get_phase(e, id);
+    set_phase(e, id, phase);
+}
+
+/// Checks that the phase `id` of kind `kind` is active and that `buyer` can
+/// mint one more token during it, records the mint and charges `buyer` the
+/// price of the phase.
+///
+/// # Errors
+///
+/// * [`SaleError::PhaseNotFound`] - When the phase does not exist.
+/// * [`SaleError::WrongPhaseKind`] - When the phase is not of kind `kind`.
+/// * [`SaleError::PhaseNotActive`] - When the current ledger is outside of
+///   the phase.
+/// * [`SaleError::PhaseLimitExceeded`] - When the phase minted its limit.
+/// * [`SaleError::WalletLimitExceeded`] - When `buyer` minted its limit.
+///
+/// # Notes
+///
+/// Authorization for `buyer` is required by the payment token.
+pub fn purchase(e: &Env, id: u32, kind: PhaseKind, buyer: &Address) {
+    let phase = get_phase(e, id);
+    if phase.kind != kind {
+        panic_with_error!(e, SaleError::WrongPhaseKind);
+    }
+
+    let ledger = e.ledger().sequence();
+    if ledger < phase.start_ledger || ledger > phase.end_ledger {
+        panic_with_error!(e, SaleError::PhaseNotActive);
+    }
+
+    let minted = phase_minted(e, id);
+    if minted >= phase.phase_limit {
+        panic_with_error!(e, SaleError::PhaseLimitExceeded);
+    }
+    let by_wallet = wallet_minted(e, id, buyer);
+    if by_wallet >= phase.wallet_limit {
+        panic_with_error!(e, SaleError::WalletLimitExceeded);
+    }
+
+    e.storage().instance().set(&SaleStorageKey::PhaseMinted(id), &(minted + 1));
+    let key = SaleStorageKey::WalletMinted(id, buyer.clone());
+    e.storage().persistent().set(&key, &(by_wallet + 1));
+    e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+
+    if phase.price > 0 {
+        token::Client::new(e, &phase.payment_token).transfer(
+            buyer,
+            &e.current_contract_address(),
+            &phase.price,
+        );
+    }
+}
+
+/// Transfers the whole balance of `token` held by the contract to `to`, and
+/// returns the transferred amount.
+pub fn withdraw(e: &Env, token: &Address, to: &Address) -> i128 {
+    let client = token::Client::new(e, token);
+    let amount = client.balance(&e.current_contract_address());
+    if amount > 0 {
+        client.transfer(&e.current_contract_address(), to, &amount);
+    }
+    amount
+}
+
+fn set_phase(e: &Env, id: u32, phase: &Phase) {
+    if phase.end_ledger < phase.start_ledger || phase.price < 0 {
+        panic_with_error!(e, SaleError::InvalidPhase);
+    }
+    e.storage().instance().set(&SaleStorageKey::Phase(id), phase);
+}
@@ -1,9 +1,608 @@
 #![cfg(test)]
 
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{
+        storage::Persistent as _, Address as _, AuthorizedFunction, AuthorizedInvocation,
+        Ledger as _,
+    },
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, String, Symbol,
+};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, DAY_IN_LEDGERS};
+use stellar_non_fungible::{sequential, NonFungibleTokenError};
+
 use super::*;
-use soroban_sdk::{symbol_short, Env};
+use crate::sale::SaleStorageKey;
+
+fn create_client<'a>(e: &Env, admin: &Address, max_supply: TokenId) -> OZStellarNFTClient<'a> {
+    let address = e.register(OZStellarNFT, (admin, max_supply));
+    OZStellarNFTClient::new(e, &address)
+}
+
+#[test]
+fn constructor_sets_metadata_and_config() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+
+    assert_eq!(client.name(), String::from_str(&e, "SoroKittens"));
+    assert_eq!(client.symbol(), String::from_str(&e, "SKT"));
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.max_supply(), 100);
+}
+
+#[test]
+fn mint_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+
+    let token_id = client.mint(&user);
+
+    assert_eq!(token_id, 0);
+    assert_eq!(client.owner_of(&token_id), user);
+    assert_eq!(client.balance(&user), 1);
+    assert_eq!(
+        client.token_uri(&token_id),
+        String::from_str(
+            &e,
+            "ipfs://bafkreigjf3tymofuq5vepmlijsglf65qprsiwykkkz6ipdgxv6fnxcje4e/0"
+        )
+    );
+}
+
+#[test]
+fn mint_requires_admin_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+
+    client.mint(&user);
+
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&e, "mint"),
+                    (user.clone(),).into_val(&e),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn mint_without_auth_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+
+    client.mint(&user);
+}
+
+#[test]
+fn mint_up_to_max_supply_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 3);
+
+    assert_eq!(client.mint(&user), 0);
+    assert_eq!(client.mint(&user), 1);
+    assert_eq!(client.mint(&user), 2);
+    assert_eq!(client.balance(&user), 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn mint_over_max_supply_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 2);
+
+    client.mint(&user);
+    client.mint(&user);
+    client.mint(&user);
+}
+
+#[test]
+fn failed_mint_over_max_supply_leaves_state_untouched() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 1);
+
+    client.mint(&user);
+
+    let result = client.try_mint(&user);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(NonFungibleTokenError::ExceededCap as u32)))
+    );
+
+    assert_eq!(client.balance(&user), 1);
+    e.as_contract(&client.address, || {
+        assert_eq!(capped::total_minted(&e), 1);
+        assert_eq!(sequential::next_token_id(&e), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn mint_with_zero_max_supply_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 0);
+
+    client.mint(&user);
+}
+
+#[test]
+fn transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+
+    let token_id = client.mint(&user);
+    client.transfer(&user, &recipient, &token_id);
+
+    assert_eq!(client.owner_of(&token_id), recipient);
+    assert_eq!(client.balance(&user), 0);
+    assert_eq!(client.balance(&recipient), 1);
+}
+
+#[test]
+fn approve_and_transfer_from_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+
+    let token_id = client.mint(&user);
+    client.approve(&user, &spender, &token_id, &1000);
+    assert_eq!(client.get_approved(&token_id), Some(spender.clone()));
+
+    client.transfer_from(&spender, &user, &recipient, &token_id);
+
+    assert_eq!(client.owner_of(&token_id), recipient);
+    assert_eq!(client.get_approved(&token_id), None);
+}
+
+fn create_payment_token<'a>(e: &Env) -> (TokenClient<'a>, StellarAssetClient<'a>) {
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    (TokenClient::new(e, &sac.address()), StellarAssetClient::new(e, &sac.address()))
+}
+
+fn phase(kind: PhaseKind, payment_token: &Address) -> Phase {
+    Phase {
+        kind,
+        start_ledger: 100,
+        end_ledger: 200,
+        price: 50,
+        payment_token: payment_token.clone(),
+        wallet_limit: 2,
+        phase_limit: 3,
+    }
+}
+
+#[test]
+fn add_phase_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, _) = create_payment_token(&e);
+
+    assert_eq!(client.add_phase(&phase(PhaseKind::Allowlist, &token.address)), 0);
+    assert_eq!(client.add_phase(&phase(PhaseKind::Public, &token.address)), 1);
+
+    assert_eq!(client.phase_count(), 2);
+    assert_eq!(client.phase(&1), phase(PhaseKind::Public, &token.address));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn add_phase_without_auth_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, _) = create_payment_token(&e);
+
+    client.add_phase(&phase(PhaseKind::Public, &token.address));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn add_phase_ending_before_start_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, _) = create_payment_token(&e);
+
+    let mut invalid = phase(PhaseKind::Public, &token.address);
+    invalid.end_ledger = invalid.start_ledger - 1;
+    client.add_phase(&invalid);
+}
+
+#[test]
+fn update_phase_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, _) = create_payment_token(&e);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    let mut updated = phase(PhaseKind::Public, &token.address);
+    updated.price = 0;
+    client.update_phase(&id, &updated);
+
+    assert_eq!(client.phase(&id), updated);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn update_missing_phase_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, _) = create_payment_token(&e);
+
+    client.update_phase(&0, &phase(PhaseKind::Public, &token.address));
+}
+
+#[test]
+fn public_mint_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(100);
+
+    let token_id = client.public_mint(&buyer, &id);
+
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(token.balance(&buyer), 950);
+    assert_eq!(token.balance(&client.address), 50);
+    assert_eq!(client.phase_minted(&id), 1);
+    assert_eq!(client.wallet_minted(&id, &buyer), 1);
+}
+
+#[test]
+fn wallet_minted_ttl_is_extended() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(100);
+    client.public_mint(&buyer, &id);
+
+    let key = SaleStorageKey::WalletMinted(id, buyer.clone());
+    let ttl = || e.as_contract(&client.address, || e.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(), BALANCE_EXTEND_AMOUNT);
+
+    // extended again when read, once below the threshold
+    e.as_contract(&client.address, || {
+        e.storage().instance().extend_ttl(3 * DAY_IN_LEDGERS, 3 * DAY_IN_LEDGERS)
+    });
+    e.ledger().set_sequence_number(100 + 2 * DAY_IN_LEDGERS);
+    assert_eq!(client.wallet_minted(&id, &buyer), 1);
+    assert_eq!(ttl(), BALANCE_EXTEND_AMOUNT);
+}
+
+#[test]
+fn free_phase_does_not_charge() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, _) = create_payment_token(&e);
+
+    let mut free = phase(PhaseKind::Public, &token.address);
+    free.price = 0;
+    let id = client.add_phase(&free);
+    e.ledger().set_sequence_number(150);
+
+    let token_id = client.public_mint(&buyer, &id);
+
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(token.balance(&buyer), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn public_mint_before_phase_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(99);
+
+    client.public_mint(&buyer, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn public_mint_after_phase_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(201);
+
+    client.public_mint(&buyer, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn public_mint_during_allowlist_phase_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Allowlist, &token.address));
+    e.ledger().set_sequence_number(100);
+
+    client.public_mint(&buyer, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn public_mint_over_wallet_limit_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(100);
+
+    client.public_mint(&buyer, &id);
+    client.public_mint(&buyer, &id);
+    client.public_mint(&buyer, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn public_mint_over_phase_limit_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer1 = Address::generate(&e);
+    let buyer2 = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer1, &1000);
+    token_admin.mint(&buyer2, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(100);
+
+    client.public_mint(&buyer1, &id);
+    client.public_mint(&buyer1, &id);
+    client.public_mint(&buyer2, &id);
+    client.public_mint(&buyer2, &id);
+}
+
+#[test]
+fn wallet_limit_is_per_phase() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let first = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    let mut second_phase = phase(PhaseKind::Public, &token.address);
+    second_phase.start_ledger = 201;
+    second_phase.end_ledger = 300;
+    let second = client.add_phase(&second_phase);
+
+    e.ledger().set_sequence_number(100);
+    client.public_mint(&buyer, &first);
+    client.public_mint(&buyer, &first);
+
+    e.ledger().set_sequence_number(201);
+    client.public_mint(&buyer, &second);
+
+    assert_eq!(client.balance(&buyer), 3);
+    assert_eq!(client.wallet_minted(&first, &buyer), 2);
+    assert_eq!(client.wallet_minted(&second, &buyer), 1);
+}
+
+#[test]
+fn public_mint_respects_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, 1);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(100);
+
+    client.public_mint(&buyer, &id);
+    let result = client.try_public_mint(&buyer, &id);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(NonFungibleTokenError::ExceededCap as u32)))
+    );
+
+    // the failed mint is not charged
+    assert_eq!(token.balance(&buyer), 950);
+    assert_eq!(client.phase_minted(&id), 1);
+}
+
+#[test]
+fn allowlist_mint_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let root = stellar_merkle::hash_pair(
+        &e,
+        &allowlist::leaf(&e, &buyer, 1),
+        &allowlist::leaf(&e, &other, 1),
+    );
+    client.set_allowlist_root(&root);
+    let id = client.add_phase(&phase(PhaseKind::Allowlist, &token.address));
+    e.ledger().set_sequence_number(100);
+
+    let token_id =
+        client.allowlist_mint(&buyer, &id, &1, &vec![&e, allowlist::leaf(&e, &other, 1)]);
+
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(token.balance(&client.address), 50);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #322)")]
+fn allowlist_mint_not_allowlisted_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    client.set_allowlist_root(&allowlist::leaf(&e, &other, 1));
+    let id = client.add_phase(&phase(PhaseKind::Allowlist, &token.address));
+    e.ledger().set_sequence_number(100);
+
+    client.allowlist_mint(&buyer, &id, &1, &vec![&e]);
+}
+
+#[test]
+fn withdraw_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, token_admin) = create_payment_token(&e);
+    token_admin.mint(&buyer, &1000);
+
+    let id = client.add_phase(&phase(PhaseKind::Public, &token.address));
+    e.ledger().set_sequence_number(100);
+    client.public_mint(&buyer, &id);
+    client.public_mint(&buyer, &id);
+
+    assert_eq!(client.withdraw(&token.address, &treasury), 100);
+
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn withdraw_without_auth_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let client = create_client(&e, &admin, 100);
+    let (token, _) = create_payment_token(&e);
+
+    client.withdraw(&token.address, &treasury);
+}
+
+#[test]
+fn placeholder_until_reveal() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, 10);
+    let revealed = String::from_str(&e, "ipfs://revealed/");
+
+    let provenance = e.crypto().sha256(&soroban_sdk::Bytes::from_slice(&e, b"ipfs://revealed/"));
+    client.set_provenance_hash(&provenance.to_bytes());
+    client.set_placeholder_uri(&String::from_str(&e, "ipfs://hidden.json"));
+    let token_id = client.mint(&user);
+
+    assert_eq!(client.token_uri(&token_id), String::from_str(&e, "ipfs://hidden.json"));
+
+    client.reveal(&revealed);
+
+    let uri = client.token_uri(&token_id);
+    assert_ne!(uri, String::from_str(&e, "ipfs://hidden.json"));
+    let expected = e.as_contract(&client.address, || {
+        Base::compose_uri_for_token(&e, revealed.clone(), Base::revealed_token_id(&e, token_id))
+    });
+    assert_eq!(uri, expected);
+}
 
 #[test]
-fn test_operations() {
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn reveal_without_auth_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 10);
 
+    client.reveal(&String::from_str(&e, "ipfs://revealed/"));
 }
@@ -17,3 +17,9 @@ pub const OWNER_TTL_THRESHOLD: u32 = OWNER_EXTEND_AMOUNT - DAY_IN_LEDGERS;
 
 pub const TOKEN_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 pub const TOKEN_TTL_THRESHOLD: u32 = TOKEN_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+pub const ROLE_EXTEND_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+pub const ROLE_TTL_THRESHOLD: u32 = ROLE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+
+pub const CLAIMED_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const CLAIMED_TTL_THRESHOLD: u32 = CLAIMED_EXTEND_AMOUNT - DAY_IN_LEDGERS;
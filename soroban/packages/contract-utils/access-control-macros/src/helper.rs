@@ -0,0 +1,70 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use stellar_macro_helpers::check_env_arg;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, FnArg, Ident, ItemFn, LitStr, PatType, Token,
+};
+
+/// Arguments of the `has_role` macro: `account, "role"`.
+struct HasRoleArgs {
+    account: Ident,
+    role: LitStr,
+}
+
+impl Parse for HasRoleArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let account = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let role = input.parse()?;
+        Ok(HasRoleArgs { account, role })
+    }
+}
+
+pub fn generate_role_check(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let HasRoleArgs { account, role } = parse_macro_input!(attr as HasRoleArgs);
+    let input_fn = parse_macro_input!(item as ItemFn);
+    let (env_ident, is_ref) = check_env_arg(&input_fn);
+    check_account_arg(&input_fn, &account);
+
+    let fn_vis = &input_fn.vis;
+    let fn_sig = &input_fn.sig;
+    let fn_block = &input_fn.block;
+    let fn_attrs = &input_fn.attrs;
+
+    let env_arg = if is_ref {
+        quote! { #env_ident }
+    } else {
+        quote! { &#env_ident }
+    };
+
+    let output = quote! {
+        #(#fn_attrs)* // retain other macros
+        #fn_vis #fn_sig {
+            #account.require_auth();
+            stellar_access_control::ensure_role(
+                #env_arg,
+                &#account,
+                &soroban_sdk::Symbol::new(#env_arg, #role),
+            );
+
+            #fn_block
+        }
+    };
+
+    output.into()
+}
+
+fn check_account_arg(input_fn: &ItemFn, account: &Ident) {
+    let found = input_fn.sig.inputs.iter().any(|arg| match arg {
+        FnArg::Typed(PatType { pat, .. }) => match &**pat {
+            syn::Pat::Ident(pat_ident) => pat_ident.ident == *account,
+            _ => false,
+        },
+        FnArg::Receiver(_) => false,
+    });
+
+    if !found {
+        panic!("function '{}' must have an argument named '{}'", input_fn.sig.ident, account);
+    }
+}
@@ -0,0 +1,34 @@
+use proc_macro::TokenStream;
+
+use crate::helper::generate_role_check;
+
+mod helper;
+
+/// Adds a role check at the beginning of the function that ensures the given
+/// account authorized the call and holds the given role.
+///
+/// This macro will inject a `require_auth()` for the account, followed by an
+/// `ensure_role` check at the start of the function body. If the account did
+/// not authorize the invocation, or does not hold the role, the function will
+/// return early with a panic.
+///
+/// # Requirement:
+///
+/// - The first argument of the decorated function must be of type `Env` or
+///   `&Env`
+/// - The account passed to the macro must be an argument of the decorated
+///   function, of type `Address` or `&Address`
+///
+/// # Example:
+///
+/// ```ignore
+/// #[has_role(caller, "minter")]
+/// pub fn mint(env: &Env, caller: Address, to: Address) {
+///     // This code will only execute if `caller` authorized the call and
+///     // holds the `minter` role
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn has_role(attr: TokenStream, item: TokenStream) -> TokenStream {
+    generate_role_check(attr, item)
+}
@@ -0,0 +1,253 @@
+use soroban_sdk::{contracterror, Address, Env, Symbol};
+
+pub trait AccessControl {
+    /// Returns `true` if `account` holds the role `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `account` - The account to check.
+    /// * `role` - The role to check.
+    fn has_role(e: &Env, account: Address, role: Symbol) -> bool {
+        crate::has_role(e, &account, &role)
+    }
+
+    /// Returns the number of accounts holding the role `role`. Use along with
+    /// `get_role_member()` to enumerate all the members of a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `role` - The role to query.
+    fn get_role_member_count(e: &Env, role: Symbol) -> u32 {
+        crate::get_role_member_count(e, &role)
+    }
+
+    /// Returns the account at `index` in the members list of the role `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `role` - The role to query.
+    /// * `index` - Index of the account in the members list of the role.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::IndexOutOfBounds`] - Occurs when `index` is
+    ///   greater or equal to the number of members of the role.
+    ///
+    /// # Notes
+    ///
+    /// The order of the members is not preserved when an account loses the
+    /// role.
+    fn get_role_member(e: &Env, role: Symbol, index: u32) -> Address {
+        crate::get_role_member(e, &role, index)
+    }
+
+    /// Returns the admin role of `role`, or `None` if no admin role is set.
+    /// The holders of the admin role can grant and revoke `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `role` - The role to query.
+    fn get_role_admin(e: &Env, role: Symbol) -> Option<Symbol> {
+        crate::get_role_admin(e, &role)
+    }
+
+    /// Returns the top-level admin, or `None` if it is not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    fn get_admin(e: &Env) -> Option<Address> {
+        crate::get_admin(e)
+    }
+
+    /// Grants the role `role` to `account`. Does nothing if `account`
+    /// already holds the role.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `caller` - The address of the caller, must be the admin or hold the
+    ///   admin role of `role`.
+    /// * `account` - The account receiving the role.
+    /// * `role` - The role to grant.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::Unauthorized`] - Occurs when `caller` is
+    ///   neither the admin nor a holder of the admin role of `role`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["role_granted", role: Symbol, account: Address]`
+    /// * data - `[caller: Address]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `caller` is required.
+    fn grant_role(e: &Env, caller: Address, account: Address, role: Symbol) {
+        crate::grant_role(e, &caller, &account, &role);
+    }
+
+    /// Revokes the role `role` from `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `caller` - The address of the caller, must be the admin or hold the
+    ///   admin role of `role`.
+    /// * `account` - The account losing the role.
+    /// * `role` - The role to revoke.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::Unauthorized`] - Occurs when `caller` is
+    ///   neither the admin nor a holder of the admin role of `role`.
+    /// * [`AccessControlError::RoleNotHeld`] - Occurs when `account` does not
+    ///   hold the role.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["role_revoked", role: Symbol, account: Address]`
+    /// * data - `[caller: Address]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `caller` is required.
+    fn revoke_role(e: &Env, caller: Address, account: Address, role: Symbol) {
+        crate::revoke_role(e, &caller, &account, &role);
+    }
+
+    /// Allows `caller` to give up the role `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `caller` - The account giving up the role.
+    /// * `role` - The role to renounce.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::RoleNotHeld`] - Occurs when `caller` does not
+    ///   hold the role.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["role_revoked", role: Symbol, account: Address]`
+    /// * data - `[caller: Address]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `caller` is required.
+    fn renounce_role(e: &Env, caller: Address, role: Symbol) {
+        crate::renounce_role(e, &caller, &role);
+    }
+
+    /// Sets `admin_role` as the admin role of `role`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `role` - The role whose admin role is set.
+    /// * `admin_role` - The new admin role.
+    ///
+    /// # Errors
+    ///
+    /// * [`AccessControlError::AdminNotSet`] - Occurs when the admin is not
+    ///   set.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["role_admin_changed", role: Symbol]`
+    /// * data - `[previous_admin_role: Option<Symbol>, new_admin_role:
+    ///   Symbol]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for the admin is required.
+    fn set_role_admin(e: &Env, role: Symbol, admin_role: Symbol) {
+        crate::set_role_admin(e, &role, &admin_role);
+    }
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccessControlError {
+    /// The caller is not allowed to perform the operation.
+    Unauthorized = 130,
+    /// The operation failed because the admin is not set.
+    AdminNotSet = 131,
+    /// The operation failed because the admin is already set.
+    AdminAlreadySet = 132,
+    /// The index is out of bounds of the members list of the role.
+    IndexOutOfBounds = 133,
+    /// The operation failed because the account does not hold the role.
+    RoleNotHeld = 134,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when a role is granted to an account.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role that was granted.
+/// * `account` - The account that received the role.
+/// * `caller` - The account that granted the role.
+///
+/// # Events
+///
+/// * topics - `["role_granted", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+pub fn emit_role_granted(e: &Env, role: &Symbol, account: &Address, caller: &Address) {
+    let topics = (Symbol::new(e, "role_granted"), role, account);
+    e.events().publish(topics, caller)
+}
+
+/// Emits an event when a role is revoked from an account.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role that was revoked.
+/// * `account` - The account that lost the role.
+/// * `caller` - The account that revoked the role.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+pub fn emit_role_revoked(e: &Env, role: &Symbol, account: &Address, caller: &Address) {
+    let topics = (Symbol::new(e, "role_revoked"), role, account);
+    e.events().publish(topics, caller)
+}
+
+/// Emits an event when the admin role of a role changes.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role whose admin role changed.
+/// * `previous_admin_role` - The previous admin role, if any.
+/// * `new_admin_role` - The new admin role.
+///
+/// # Events
+///
+/// * topics - `["role_admin_changed", role: Symbol]`
+/// * data - `[previous_admin_role: Option<Symbol>, new_admin_role: Symbol]`
+pub fn emit_role_admin_changed(
+    e: &Env,
+    role: &Symbol,
+    previous_admin_role: &Option<Symbol>,
+    new_admin_role: &Symbol,
+) {
+    let topics = (Symbol::new(e, "role_admin_changed"), role);
+    e.events().publish(topics, (previous_admin_role.clone(), new_admin_role))
+}
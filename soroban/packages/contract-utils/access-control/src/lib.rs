@@ -0,0 +1,81 @@
+//! Access Control Contract Module.
+//!
+//! This contract module provides a role-based access control mechanism, where
+//! accounts can be granted roles (e.g. `minter`, `pauser`, `upgrader`), and
+//! functions can be restricted to the holders of a specific role.
+//!
+//! By implementing the trait [`AccessControl`] for your contract, you can
+//! safely integrate the Access Control functionality. The trait
+//! [`AccessControl`] has the following methods:
+//! - [`has_role()`]
+//! - [`get_role_member_count()`]
+//! - [`get_role_member()`]
+//! - [`get_role_admin()`]
+//! - [`get_admin()`]
+//! - [`grant_role()`]
+//! - [`revoke_role()`]
+//! - [`renounce_role()`]
+//! - [`set_role_admin()`]
+//!
+//! Roles are identified by a [`soroban_sdk::Symbol`]. The contract has a
+//! single top-level admin, which can grant and revoke every role. In addition,
+//! each role can have an admin role: the holders of the admin role can grant
+//! and revoke the role they administer. This allows building role hierarchies,
+//! for example a `minter_admin` role managing the `minter` role.
+//!
+//! The members of each role are enumerable with [`get_role_member_count()`]
+//! and [`get_role_member()`].
+//!
+//! We also provide the macro `has_role`. This macro acts as a guard for your
+//! functions: it requires the authorization of the given argument, and checks
+//! that it holds the given role. For example:
+//!
+//! ```ignore
+//! #[has_role(caller, "minter")]
+//! fn mint(e: &Env, caller: Address, to: Address) {
+//!     /* this body will execute ONLY when `caller` authorized the call and
+//!      * holds the `minter` role */
+//! }
+//! ```
+//!
+//! The admin is expected to be set once, most likely in the constructor, with
+//! [`set_admin()`]. Initial roles can be granted in the constructor with
+//! [`grant_role_no_auth()`]:
+//!
+//! ```ignore
+//! pub fn __constructor(e: &Env, admin: Address, minter: Address) {
+//!     stellar_access_control::set_admin(e, &admin);
+//!     stellar_access_control::grant_role_no_auth(
+//!         e,
+//!         &admin,
+//!         &minter,
+//!         &Symbol::new(e, "minter"),
+//!     );
+//! }
+//! ```
+//!
+//! You can opt-out of [`AccessControl`] trait, and use `storage::*` functions
+//! directly in your contract if you want more customizability. But we
+//! encourage the use of [`AccessControl`] trait instead, for the same reasons
+//! as for the `Pausable` trait: standardization, no additional cost, and
+//! better organized code.
+
+#![no_std]
+
+mod access_control;
+mod storage;
+
+pub use crate::{
+    access_control::{
+        emit_role_admin_changed, emit_role_granted, emit_role_revoked, AccessControl,
+        AccessControlError,
+    },
+    storage::{
+        enforce_admin_auth, ensure_role, get_admin, get_role_admin, get_role_member,
+        get_role_member_count, grant_role, grant_role_no_auth, has_role, renounce_role,
+        revoke_role, revoke_role_no_auth, set_admin, set_role_admin, set_role_admin_no_auth,
+        AccessControlStorageKey, RoleAccountKey,
+    },
+};
+
+mod test;
@@ -0,0 +1,452 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+use stellar_constants::{ROLE_EXTEND_AMOUNT, ROLE_TTL_THRESHOLD};
+
+use crate::{
+    access_control::AccessControlError, emit_role_admin_changed, emit_role_granted,
+    emit_role_revoked,
+};
+
+#[contracttype]
+pub struct RoleAccountKey {
+    pub role: Symbol,
+    pub index: u32,
+}
+
+/// Storage keys for the data associated with `AccessControl`
+#[contracttype]
+pub enum AccessControlStorageKey {
+    Admin,
+    RoleAccounts(RoleAccountKey),
+    HasRole(/* account */ Address, /* role */ Symbol),
+    RoleAccountsCount(/* role */ Symbol),
+    RoleAdmin(/* role */ Symbol),
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the top-level admin, or `None` if it is not set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn get_admin(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&AccessControlStorageKey::Admin)
+}
+
+/// Returns `true` if `account` holds the role `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The account to check.
+/// * `role` - The role to check.
+pub fn has_role(e: &Env, account: &Address, role: &Symbol) -> bool {
+    let key = AccessControlStorageKey::HasRole(account.clone(), role.clone());
+    if e.storage().persistent().has(&key) {
+        e.storage().persistent().extend_ttl(&key, ROLE_TTL_THRESHOLD, ROLE_EXTEND_AMOUNT);
+        true
+    } else {
+        false
+    }
+}
+
+/// Returns the number of accounts holding the role `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role to query.
+pub fn get_role_member_count(e: &Env, role: &Symbol) -> u32 {
+    let key = AccessControlStorageKey::RoleAccountsCount(role.clone());
+    if let Some(count) = e.storage().persistent().get::<_, u32>(&key) {
+        e.storage().persistent().extend_ttl(&key, ROLE_TTL_THRESHOLD, ROLE_EXTEND_AMOUNT);
+        count
+    } else {
+        0
+    }
+}
+
+/// Returns the account at `index` in the members list of the role `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role to query.
+/// * `index` - Index of the account in the members list of the role.
+///
+/// # Errors
+///
+/// * [`AccessControlError::IndexOutOfBounds`] - Occurs when `index` is
+///   greater or equal to the number of members of the role.
+pub fn get_role_member(e: &Env, role: &Symbol, index: u32) -> Address {
+    let key = AccessControlStorageKey::RoleAccounts(RoleAccountKey { role: role.clone(), index });
+    let Some(account) = e.storage().persistent().get::<_, Address>(&key) else {
+        panic_with_error!(e, AccessControlError::IndexOutOfBounds);
+    };
+    e.storage().persistent().extend_ttl(&key, ROLE_TTL_THRESHOLD, ROLE_EXTEND_AMOUNT);
+
+    account
+}
+
+/// Returns the admin role of `role`, or `None` if no admin role is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role to query.
+pub fn get_role_admin(e: &Env, role: &Symbol) -> Option<Symbol> {
+    let key = AccessControlStorageKey::RoleAdmin(role.clone());
+    let admin_role = e.storage().persistent().get::<_, Symbol>(&key);
+    if admin_role.is_some() {
+        e.storage().persistent().extend_ttl(&key, ROLE_TTL_THRESHOLD, ROLE_EXTEND_AMOUNT);
+    }
+    admin_role
+}
+
+// ################## CHANGE STATE ##################
+
+/// Sets the top-level admin.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `admin` - The admin.
+///
+/// # Errors
+///
+/// * [`AccessControlError::AdminAlreadySet`] - Occurs when the admin is
+///   already set.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls. You want to
+/// invoke it most likely from the constructor.
+pub fn set_admin(e: &Env, admin: &Address) {
+    if e.storage().instance().has(&AccessControlStorageKey::Admin) {
+        panic_with_error!(e, AccessControlError::AdminAlreadySet);
+    }
+    e.storage().instance().set(&AccessControlStorageKey::Admin, admin);
+}
+
+/// Grants the role `role` to `account`. Does nothing if `account` already
+/// holds the role.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller, must be the admin or hold the
+///   admin role of `role`.
+/// * `account` - The account receiving the role.
+/// * `role` - The role to grant.
+///
+/// # Errors
+///
+/// * refer to [`ensure_if_admin_or_admin_role`] errors.
+///
+/// # Events
+///
+/// * topics - `["role_granted", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+///
+/// # Notes
+///
+/// Authorization for `caller` is required.
+pub fn grant_role(e: &Env, caller: &Address, account: &Address, role: &Symbol) {
+    caller.require_auth();
+    ensure_if_admin_or_admin_role(e, caller, role);
+    grant_role_no_auth(e, caller, account, role);
+}
+
+/// Low-level function to grant the role `role` to `account` without checking
+/// the authorization of `caller`. Does nothing if `account` already holds the
+/// role.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address reported as the granter in the event.
+/// * `account` - The account receiving the role.
+/// * `role` - The role to grant.
+///
+/// # Events
+///
+/// * topics - `["role_granted", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls. It is meant to
+/// grant the initial roles from the constructor, or to be used with a custom
+/// authorization logic.
+pub fn grant_role_no_auth(e: &Env, caller: &Address, account: &Address, role: &Symbol) {
+    if has_role(e, account, role) {
+        return;
+    }
+
+    add_to_role_enumeration(e, account, role);
+    emit_role_granted(e, role, account, caller);
+}
+
+/// Revokes the role `role` from `account`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address of the caller, must be the admin or hold the
+///   admin role of `role`.
+/// * `account` - The account losing the role.
+/// * `role` - The role to revoke.
+///
+/// # Errors
+///
+/// * refer to [`ensure_if_admin_or_admin_role`] errors.
+/// * refer to [`revoke_role_no_auth`] errors.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+///
+/// # Notes
+///
+/// Authorization for `caller` is required.
+pub fn revoke_role(e: &Env, caller: &Address, account: &Address, role: &Symbol) {
+    caller.require_auth();
+    ensure_if_admin_or_admin_role(e, caller, role);
+    revoke_role_no_auth(e, caller, account, role);
+}
+
+/// Low-level function to revoke the role `role` from `account` without
+/// checking the authorization of `caller`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The address reported as the revoker in the event.
+/// * `account` - The account losing the role.
+/// * `role` - The role to revoke.
+///
+/// # Errors
+///
+/// * [`AccessControlError::RoleNotHeld`] - Occurs when `account` does not
+///   hold the role.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls.
+pub fn revoke_role_no_auth(e: &Env, caller: &Address, account: &Address, role: &Symbol) {
+    if !has_role(e, account, role) {
+        panic_with_error!(e, AccessControlError::RoleNotHeld);
+    }
+
+    remove_from_role_enumeration(e, account, role);
+    emit_role_revoked(e, role, account, caller);
+}
+
+/// Allows `caller` to give up the role `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The account giving up the role.
+/// * `role` - The role to renounce.
+///
+/// # Errors
+///
+/// * refer to [`revoke_role_no_auth`] errors.
+///
+/// # Events
+///
+/// * topics - `["role_revoked", role: Symbol, account: Address]`
+/// * data - `[caller: Address]`
+///
+/// # Notes
+///
+/// Authorization for `caller` is required.
+pub fn renounce_role(e: &Env, caller: &Address, role: &Symbol) {
+    caller.require_auth();
+    revoke_role_no_auth(e, caller, caller, role);
+}
+
+/// Sets `admin_role` as the admin role of `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role whose admin role is set.
+/// * `admin_role` - The new admin role.
+///
+/// # Errors
+///
+/// * refer to [`enforce_admin_auth`] errors.
+///
+/// # Events
+///
+/// * topics - `["role_admin_changed", role: Symbol]`
+/// * data - `[previous_admin_role: Option<Symbol>, new_admin_role: Symbol]`
+///
+/// # Notes
+///
+/// Authorization for the admin is required.
+pub fn set_role_admin(e: &Env, role: &Symbol, admin_role: &Symbol) {
+    enforce_admin_auth(e);
+    set_role_admin_no_auth(e, role, admin_role);
+}
+
+/// Low-level function to set `admin_role` as the admin role of `role` without
+/// any authorization check.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `role` - The role whose admin role is set.
+/// * `admin_role` - The new admin role.
+///
+/// # Events
+///
+/// * topics - `["role_admin_changed", role: Symbol]`
+/// * data - `[previous_admin_role: Option<Symbol>, new_admin_role: Symbol]`
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls. You want to
+/// invoke it most likely from the constructor.
+pub fn set_role_admin_no_auth(e: &Env, role: &Symbol, admin_role: &Symbol) {
+    let previous_admin_role = get_role_admin(e, role);
+    let key = AccessControlStorageKey::RoleAdmin(role.clone());
+    e.storage().persistent().set(&key, admin_role);
+    emit_role_admin_changed(e, role, &previous_admin_role, admin_role);
+}
+
+// ################## LOW-LEVEL HELPERS ##################
+
+/// Helper to make a function callable only by the admin. Requires the
+/// authorization of the admin and returns it.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+///
+/// # Errors
+///
+/// * [`AccessControlError::AdminNotSet`] - Occurs when the admin is not set.
+pub fn enforce_admin_auth(e: &Env) -> Address {
+    let Some(admin) = get_admin(e) else {
+        panic_with_error!(e, AccessControlError::AdminNotSet);
+    };
+    admin.require_auth();
+    admin
+}
+
+/// Ensures `caller` holds the role `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The account to check.
+/// * `role` - The required role.
+///
+/// # Errors
+///
+/// * [`AccessControlError::Unauthorized`] - Occurs when `caller` does not
+///   hold the role.
+///
+/// # Notes
+///
+/// This function does not require the authorization of `caller`, it has to
+/// be handled separately.
+pub fn ensure_role(e: &Env, caller: &Address, role: &Symbol) {
+    if !has_role(e, caller, role) {
+        panic_with_error!(e, AccessControlError::Unauthorized);
+    }
+}
+
+/// Ensures `caller` is either the admin, or holds the admin role of `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `caller` - The account to check.
+/// * `role` - The role to be managed.
+///
+/// # Errors
+///
+/// * [`AccessControlError::Unauthorized`] - Occurs when `caller` is neither
+///   the admin nor a holder of the admin role of `role`.
+fn ensure_if_admin_or_admin_role(e: &Env, caller: &Address, role: &Symbol) {
+    let is_admin = get_admin(e).is_some_and(|admin| admin == *caller);
+    let is_role_admin =
+        get_role_admin(e, role).is_some_and(|admin_role| has_role(e, caller, &admin_role));
+
+    if !is_admin && !is_role_admin {
+        panic_with_error!(e, AccessControlError::Unauthorized);
+    }
+}
+
+/// Appends `account` to the members list of `role`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The account to add.
+/// * `role` - The role.
+fn add_to_role_enumeration(e: &Env, account: &Address, role: &Symbol) {
+    let count = get_role_member_count(e, role);
+
+    let member_key =
+        AccessControlStorageKey::RoleAccounts(RoleAccountKey { role: role.clone(), index: count });
+    e.storage().persistent().set(&member_key, account);
+
+    let has_role_key = AccessControlStorageKey::HasRole(account.clone(), role.clone());
+    e.storage().persistent().set(&has_role_key, &count);
+
+    let count_key = AccessControlStorageKey::RoleAccountsCount(role.clone());
+    e.storage().persistent().set(&count_key, &(count + 1));
+}
+
+/// Removes `account` from the members list of `role`. The last member of the
+/// list is moved to the freed index, so the list stays contiguous.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `account` - The account to remove.
+/// * `role` - The role.
+fn remove_from_role_enumeration(e: &Env, account: &Address, role: &Symbol) {
+    let has_role_key = AccessControlStorageKey::HasRole(account.clone(), role.clone());
+    let Some(to_be_removed_index) = e.storage().persistent().get::<_, u32>(&has_role_key) else {
+        panic_with_error!(e, AccessControlError::RoleNotHeld);
+    };
+
+    let count = get_role_member_count(e, role);
+    let last_index = count - 1;
+
+    // Move the last member to the freed index, unless it is the removed one.
+    if to_be_removed_index != last_index {
+        let last_account = get_role_member(e, role, last_index);
+
+        let moved_key = AccessControlStorageKey::RoleAccounts(RoleAccountKey {
+            role: role.clone(),
+            index: to_be_removed_index,
+        });
+        e.storage().persistent().set(&moved_key, &last_account);
+
+        let last_has_role_key = AccessControlStorageKey::HasRole(last_account, role.clone());
+        e.storage().persistent().set(&last_has_role_key, &to_be_removed_index);
+    }
+
+    let last_key = AccessControlStorageKey::RoleAccounts(RoleAccountKey {
+        role: role.clone(),
+        index: last_index,
+    });
+    e.storage().persistent().remove(&last_key);
+    e.storage().persistent().remove(&has_role_key);
+
+    let count_key = AccessControlStorageKey::RoleAccountsCount(role.clone());
+    e.storage().persistent().set(&count_key, &last_index);
+}
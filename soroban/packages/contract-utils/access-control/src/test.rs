@@ -0,0 +1,333 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::storage::{
+    get_admin, get_role_admin, get_role_member, get_role_member_count, grant_role,
+    grant_role_no_auth, has_role, renounce_role, revoke_role, set_admin, set_role_admin,
+};
+
+#[contract]
+struct MockContract;
+
+fn setup(e: &Env) -> (Address, Address) {
+    let address = e.register(MockContract, ());
+    let admin = Address::generate(e);
+    e.as_contract(&address, || set_admin(e, &admin));
+    (address, admin)
+}
+
+#[test]
+fn initial_state() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        assert_eq!(get_admin(&e), None);
+        assert!(!has_role(&e, &account, &minter));
+        assert_eq!(get_role_member_count(&e, &minter), 0);
+        assert_eq!(get_role_admin(&e, &minter), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #132)")]
+fn errors_set_admin_when_already_set() {
+    let e = Env::default();
+    let (address, admin) = setup(&e);
+
+    e.as_contract(&address, || set_admin(&e, &admin));
+}
+
+#[test]
+fn admin_grant_role_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, admin) = setup(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &account, &minter);
+
+        assert!(has_role(&e, &account, &minter));
+        assert_eq!(get_role_member_count(&e, &minter), 1);
+        assert_eq!(get_role_member(&e, &minter, 0), account);
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        Symbol::new(&e, "role_granted").into_val(&e),
+                        minter.into_val(&e),
+                        account.into_val(&e)
+                    ],
+                    admin.into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+fn grant_role_twice_is_noop() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, admin) = setup(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &account, &minter);
+    });
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &account, &minter);
+
+        assert_eq!(get_role_member_count(&e, &minter), 1);
+        assert_eq!(e.events().all().len(), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #130)")]
+fn errors_grant_role_from_unauthorized_caller() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, _) = setup(&e);
+    let caller = Address::generate(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        grant_role(&e, &caller, &account, &minter);
+    });
+}
+
+#[test]
+fn role_admin_can_grant_and_revoke_role() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, admin) = setup(&e);
+    let manager = Address::generate(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+    let minter_admin = Symbol::new(&e, "minter_admin");
+
+    e.as_contract(&address, || {
+        set_role_admin(&e, &minter, &minter_admin);
+        assert_eq!(get_role_admin(&e, &minter), Some(minter_admin.clone()));
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        Symbol::new(&e, "role_admin_changed").into_val(&e),
+                        minter.into_val(&e)
+                    ],
+                    (None::<Symbol>, minter_admin.clone()).into_val(&e)
+                )
+            ]
+        );
+    });
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &manager, &minter_admin);
+    });
+
+    e.as_contract(&address, || {
+        grant_role(&e, &manager, &account, &minter);
+        assert!(has_role(&e, &account, &minter));
+    });
+
+    e.as_contract(&address, || {
+        revoke_role(&e, &manager, &account, &minter);
+        assert!(!has_role(&e, &account, &minter));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #130)")]
+fn errors_role_admin_cannot_grant_other_roles() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, admin) = setup(&e);
+    let manager = Address::generate(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+    let minter_admin = Symbol::new(&e, "minter_admin");
+    let pauser = Symbol::new(&e, "pauser");
+
+    e.as_contract(&address, || {
+        set_role_admin(&e, &minter, &minter_admin);
+        grant_role_no_auth(&e, &admin, &manager, &minter_admin);
+    });
+
+    e.as_contract(&address, || {
+        grant_role(&e, &manager, &account, &pauser);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #131)")]
+fn errors_set_role_admin_without_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_role_admin(&e, &Symbol::new(&e, "minter"), &Symbol::new(&e, "minter_admin"));
+    });
+}
+
+#[test]
+fn revoke_role_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, admin) = setup(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &account, &minter);
+    });
+
+    e.as_contract(&address, || {
+        revoke_role(&e, &admin, &account, &minter);
+
+        assert!(!has_role(&e, &account, &minter));
+        assert_eq!(get_role_member_count(&e, &minter), 0);
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![
+                        &e,
+                        Symbol::new(&e, "role_revoked").into_val(&e),
+                        minter.into_val(&e),
+                        account.into_val(&e)
+                    ],
+                    admin.into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #134)")]
+fn errors_revoke_role_not_held() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, admin) = setup(&e);
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        revoke_role(&e, &admin, &account, &Symbol::new(&e, "minter"));
+    });
+}
+
+#[test]
+fn renounce_role_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, admin) = setup(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        grant_role(&e, &admin, &account, &minter);
+        renounce_role(&e, &account, &minter);
+
+        assert!(!has_role(&e, &account, &minter));
+        assert_eq!(get_role_member_count(&e, &minter), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #134)")]
+fn errors_renounce_role_not_held() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (address, _) = setup(&e);
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        renounce_role(&e, &account, &Symbol::new(&e, "minter"));
+    });
+}
+
+#[test]
+fn role_enumeration_works() {
+    let e = Env::default();
+    let (address, admin) = setup(&e);
+    let account1 = Address::generate(&e);
+    let account2 = Address::generate(&e);
+    let account3 = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        grant_role_no_auth(&e, &admin, &account1, &minter);
+        grant_role_no_auth(&e, &admin, &account2, &minter);
+        grant_role_no_auth(&e, &admin, &account3, &minter);
+
+        assert_eq!(get_role_member_count(&e, &minter), 3);
+        assert_eq!(get_role_member(&e, &minter, 0), account1);
+        assert_eq!(get_role_member(&e, &minter, 1), account2);
+        assert_eq!(get_role_member(&e, &minter, 2), account3);
+    });
+
+    e.mock_all_auths();
+    e.as_contract(&address, || {
+        // the last member takes the place of the removed one
+        revoke_role(&e, &admin, &account1, &minter);
+
+        assert_eq!(get_role_member_count(&e, &minter), 2);
+        assert_eq!(get_role_member(&e, &minter, 0), account3);
+        assert_eq!(get_role_member(&e, &minter, 1), account2);
+    });
+
+    e.as_contract(&address, || {
+        // the moved member can still be removed
+        revoke_role(&e, &admin, &account3, &minter);
+
+        assert_eq!(get_role_member_count(&e, &minter), 1);
+        assert_eq!(get_role_member(&e, &minter, 0), account2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #133)")]
+fn errors_get_role_member_out_of_bounds() {
+    let e = Env::default();
+    let (address, admin) = setup(&e);
+    let account = Address::generate(&e);
+    let minter = Symbol::new(&e, "minter");
+
+    e.as_contract(&address, || {
+        grant_role_no_auth(&e, &admin, &account, &minter);
+        get_role_member(&e, &minter, 1);
+    });
+}
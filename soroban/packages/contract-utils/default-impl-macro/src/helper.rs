@@ -32,15 +32,64 @@ fn get_default_methods(trait_name: &str) -> Vec<syn::ImplItem> {
             syn::parse_quote! { fn name(e: &Env) -> String { Self::ContractType::name(e) } },
             syn::parse_quote! { fn symbol(e: &Env) -> String { Self::ContractType::symbol(e) } },
         ],
+        "NonFungibleApprovals" => vec![
+            syn::parse_quote! { fn operators_of(e: &Env, owner: Address) -> Vec<(Address, u32)> { Self::ContractType::operators_of(e, &owner) } },
+            syn::parse_quote! { fn approval_of(e: &Env, token_id: TokenId) -> Option<ApprovalData> { Self::ContractType::approval_of(e, token_id) } },
+            syn::parse_quote! { fn revoke_approval(e: &Env, approver: Address, token_id: TokenId) { Self::ContractType::revoke_approval(e, &approver, token_id); } },
+        ],
+        "NonFungibleBatchTransfer" => vec![
+            syn::parse_quote! { fn batch_transfer(e: &Env, from: Address, to: Address, token_ids: Vec<TokenId>) { Self::ContractType::batch_transfer(e, &from, &to, &token_ids); } },
+            syn::parse_quote! { fn batch_transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_ids: Vec<TokenId>) { Self::ContractType::batch_transfer_from(e, &spender, &from, &to, &token_ids); } },
+            syn::parse_quote! { fn batch_approve(e: &Env, approver: Address, approved: Address, token_ids: Vec<TokenId>, live_until_ledger: u32) { Self::ContractType::batch_approve(e, &approver, &approved, &token_ids, live_until_ledger); } },
+        ],
         "NonFungibleBurnable" => vec![
-            syn::parse_quote! { fn burn(e: &Env, from: Address, token_id: TokenId) { Base::burn(e, &from, token_id); } },
-            syn::parse_quote! { fn burn_from(e: &Env, spender: Address, from: Address, token_id: TokenId) { Base::burn_from(e, &spender, &from, token_id); } },
+            syn::parse_quote! { fn burn(e: &Env, from: Address, token_id: TokenId) { Self::ContractType::burn(e, &from, token_id); } },
+            syn::parse_quote! { fn burn_from(e: &Env, spender: Address, from: Address, token_id: TokenId) { Self::ContractType::burn_from(e, &spender, &from, token_id); } },
         ],
         "NonFungibleEnumerable" => vec![
             syn::parse_quote! { fn total_supply(e: &Env) -> Balance { Enumerable::total_supply(e) } },
             syn::parse_quote! { fn get_owner_token_id(e: &Env, owner: Address, index: TokenId) -> TokenId { Enumerable::get_owner_token_id(e, &owner, index) } },
             syn::parse_quote! { fn get_token_id(e: &Env, index: TokenId) -> TokenId { Enumerable::get_token_id(e, index) } },
+            syn::parse_quote! { fn tokens_of_owner(e: &Env, owner: Address, start: TokenId, limit: u32) -> Vec<TokenId> { Enumerable::tokens_of_owner(e, &owner, start, limit) } },
+            syn::parse_quote! { fn all_tokens(e: &Env, start: TokenId, limit: u32) -> Vec<TokenId> { Enumerable::all_tokens(e, start, limit) } },
+        ],
+        "NonFungibleEnumerableConsecutive" => vec![
+            syn::parse_quote! { fn total_supply(e: &Env) -> Balance { EnumerableConsecutive::total_supply(e) } },
+            syn::parse_quote! { fn get_owner_token_id(e: &Env, owner: Address, index: TokenId) -> TokenId { EnumerableConsecutive::get_owner_token_id(e, &owner, index) } },
+            syn::parse_quote! { fn get_token_id(e: &Env, index: TokenId) -> TokenId { EnumerableConsecutive::get_token_id(e, index) } },
+        ],
+        "NonFungibleRoyalties" => vec![
+            syn::parse_quote! { fn royalty_info(e: &Env, token_id: TokenId, sale_price: i128) -> (Address, i128) { Base::royalty_info(e, token_id, sale_price) } },
+        ],
+        "NonFungibleRentable" => vec![
+            syn::parse_quote! { fn set_user(e: &Env, caller: Address, token_id: TokenId, user: Address, expires_ledger: u32) { Self::ContractType::set_user(e, &caller, token_id, &user, expires_ledger); } },
+            syn::parse_quote! { fn user_of(e: &Env, token_id: TokenId) -> Option<Address> { Base::user_of(e, token_id) } },
+            syn::parse_quote! { fn user_expires(e: &Env, token_id: TokenId) -> u32 { Base::user_expires(e, token_id) } },
+        ],
+        "NonFungibleSafeTransfer" => vec![
+            syn::parse_quote! { fn safe_transfer(e: &Env, from: Address, to: Address, token_id: TokenId, data: Bytes) { Self::ContractType::safe_transfer(e, &from, &to, token_id, &data); } },
+            syn::parse_quote! { fn safe_transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: TokenId, data: Bytes) { Self::ContractType::safe_transfer_from(e, &spender, &from, &to, token_id, &data); } },
+        ],
+        "MultiToken" => vec![
+            syn::parse_quote! { fn balance_of(e: &Env, account: Address, id: TokenId) -> i128 { stellar_multi_token::balance_of(e, &account, id) } },
+            syn::parse_quote! { fn balance_of_batch(e: &Env, accounts: Vec<Address>, ids: Vec<TokenId>) -> Vec<i128> { stellar_multi_token::balance_of_batch(e, &accounts, &ids) } },
+            syn::parse_quote! { fn total_supply(e: &Env, id: TokenId) -> i128 { stellar_multi_token::total_supply(e, id) } },
+            syn::parse_quote! { fn transfer(e: &Env, from: Address, to: Address, id: TokenId, amount: i128) { stellar_multi_token::transfer(e, &from, &to, id, amount); } },
+            syn::parse_quote! { fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, id: TokenId, amount: i128) { stellar_multi_token::transfer_from(e, &spender, &from, &to, id, amount); } },
+            syn::parse_quote! { fn batch_transfer(e: &Env, from: Address, to: Address, ids: Vec<TokenId>, amounts: Vec<i128>) { stellar_multi_token::batch_transfer(e, &from, &to, &ids, &amounts); } },
+            syn::parse_quote! { fn batch_transfer_from(e: &Env, spender: Address, from: Address, to: Address, ids: Vec<TokenId>, amounts: Vec<i128>) { stellar_multi_token::batch_transfer_from(e, &spender, &from, &to, &ids, &amounts); } },
+            syn::parse_quote! { fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) { stellar_multi_token::approve_for_all(e, &owner, &operator, live_until_ledger); } },
+            syn::parse_quote! { fn is_approved_for_all(e: &Env, owner: Address, operator: Address) -> bool { stellar_multi_token::is_approved_for_all(e, &owner, &operator) } },
+            syn::parse_quote! { fn uri(e: &Env, id: TokenId) -> String { stellar_multi_token::uri(e, id) } },
+        ],
+        "MultiTokenBurnable" => vec![
+            syn::parse_quote! { fn burn(e: &Env, from: Address, id: TokenId, amount: i128) { stellar_multi_token::burnable::burn(e, &from, id, amount); } },
+            syn::parse_quote! { fn burn_from(e: &Env, spender: Address, from: Address, id: TokenId, amount: i128) { stellar_multi_token::burnable::burn_from(e, &spender, &from, id, amount); } },
+            syn::parse_quote! { fn batch_burn(e: &Env, from: Address, ids: Vec<TokenId>, amounts: Vec<i128>) { stellar_multi_token::burnable::batch_burn(e, &from, &ids, &amounts); } },
+            syn::parse_quote! { fn batch_burn_from(e: &Env, spender: Address, from: Address, ids: Vec<TokenId>, amounts: Vec<i128>) { stellar_multi_token::burnable::batch_burn_from(e, &spender, &from, &ids, &amounts); } },
         ],
+        // marker trait, the soulbound behavior comes from the `Soulbound` contract type
+        "NonFungibleSoulbound" => vec![],
         not_supported => {
             panic!("Trait {} is not supported by #[default_impl]", not_supported)
         }
@@ -44,9 +44,18 @@ mod helper;
 /// This macro works for the following traits:
 /// - `FungibleToken`
 /// - `FungibleBurnable`
+/// - `MultiToken`
+/// - `MultiTokenBurnable`
 /// - `NonFungibleToken`
+/// - `NonFungibleApprovals`
+/// - `NonFungibleBatchTransfer`
 /// - `NonFungibleBurnable`
 /// - `NonFungibleEnumerable`
+/// - `NonFungibleEnumerableConsecutive`
+/// - `NonFungibleRentable`
+/// - `NonFungibleRoyalties`
+/// - `NonFungibleSafeTransfer`
+/// - `NonFungibleSoulbound`
 #[proc_macro_attribute]
 pub fn default_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
     generate_default_impl(item)
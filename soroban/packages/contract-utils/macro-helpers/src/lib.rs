@@ -0,0 +1,58 @@
+//! Helpers shared by the procedural macros of the contract utilities, e.g.
+//! `stellar-pausable-macros` or `stellar-ownable-macros`.
+//!
+//! A `proc-macro` crate can only export macros, so the helpers live in this
+//! regular crate.
+
+use syn::{FnArg, ItemFn, PatType, Type};
+
+/// Checks that the first argument of `input_fn` is of type `Env` or `&Env`,
+/// and returns its identifier along with whether it is a reference.
+///
+/// # Panics
+///
+/// * If `input_fn` has no argument.
+/// * If the first argument is not an identifier of type `Env` or `&Env`.
+pub fn check_env_arg(input_fn: &ItemFn) -> (syn::Ident, bool) {
+    // Get the first argument
+    let first_arg = input_fn.sig.inputs.first().unwrap_or_else(|| {
+        panic!("function '{}' must have at least one argument", input_fn.sig.ident)
+    });
+
+    // Extract the pattern and type from the argument
+    let FnArg::Typed(PatType { pat, ty, .. }) = first_arg else {
+        panic!("first argument of function '{}' must be a typed parameter", input_fn.sig.ident);
+    };
+
+    // Get the identifier from the pattern
+    let syn::Pat::Ident(pat_ident) = &**pat else {
+        panic!("first argument of function '{}' must be an identifier", input_fn.sig.ident);
+    };
+    let ident = pat_ident.ident.clone();
+
+    // Check if the type is Env or &Env
+    let is_ref = match &**ty {
+        Type::Reference(type_ref) => {
+            let Type::Path(path) = &*type_ref.elem else {
+                panic!("first argument of function '{}' must be Env or &Env", input_fn.sig.ident);
+            };
+            check_is_env(path, &input_fn.sig.ident);
+            true
+        }
+        Type::Path(path) => {
+            check_is_env(path, &input_fn.sig.ident);
+            false
+        }
+        _ => panic!("first argument of function '{}' must be Env or &Env", input_fn.sig.ident),
+    };
+
+    (ident, is_ref)
+}
+
+fn check_is_env(path: &syn::TypePath, fn_name: &syn::Ident) {
+    let is_env = path.path.segments.last().map(|seg| seg.ident == "Env").unwrap_or(false);
+
+    if !is_env {
+        panic!("first argument of function '{}' must be Env or &Env", fn_name);
+    }
+}
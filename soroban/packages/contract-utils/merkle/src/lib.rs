@@ -0,0 +1,48 @@
+//! Merkle Proof Utilities.
+//!
+//! This module allows verifying that a leaf belongs to a Merkle tree, given
+//! the root of the tree and a proof. It is most useful for allowlists and
+//! airdrops: the set of eligible accounts is committed to with a single
+//! 32-byte root, instead of storing every account in the contract storage.
+//!
+//! The tree is expected to be built with `sha256`, hashing the pairs of nodes
+//! in sorted order. Thanks to the sorting, proofs do not need to carry the
+//! position (left or right) of the sibling nodes. How the leaves are encoded
+//! is left to the implementor, but they MUST be hashed with [`hash_leaf()`]
+//! (or [`hash_indexed_leaf()`]) before being inserted into the tree. The
+//! leaves are hashed twice, so that they can't be mistaken for inner nodes.
+//!
+//! The module provides:
+//! - [`verify()`] and [`process_proof()`] to check a proof against any root,
+//! - [`set_root()`] and [`root()`] to store the root of a distribution,
+//! - [`claim()`] to verify the leaf at an index against the stored root and
+//!   mark the index as claimed in a bitmap, so that every leaf can be claimed
+//!   only once. The index is part of the verified leaf (see
+//!   [`hash_indexed_leaf()`]), so a leaf can't be claimed under another index.
+//!
+//! ```ignore
+//! pub fn claim(e: &Env, index: u32, account: Address, amount: i128, proof: Vec<BytesN<32>>) {
+//!     let mut data = account.to_xdr(e);
+//!     data.extend_from_array(&amount.to_be_bytes());
+//!
+//!     // verifies `hash_indexed_leaf(e, index, &data)`
+//!     stellar_merkle::claim(e, index, &data, &proof);
+//!     /* transfer `amount` to `account` */
+//! }
+//! ```
+
+#![no_std]
+
+mod merkle;
+mod storage;
+
+pub use crate::{
+    merkle::{
+        emit_root_set, hash_indexed_leaf, hash_leaf, hash_pair, process_proof, verify, MerkleError,
+    },
+    storage::{
+        claim, is_claimed, root, set_claimed, set_root, verify_against_root, MerkleStorageKey,
+    },
+};
+
+mod test;
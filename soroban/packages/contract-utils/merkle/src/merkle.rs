@@ -0,0 +1,109 @@
+use soroban_sdk::{contracterror, Bytes, BytesN, Env, Symbol, Vec};
+
+/// Hashes the encoded leaf `data` twice with `sha256`, i.e.
+/// `sha256(sha256(data))`.
+///
+/// The inner nodes are the hash of 64 bytes (a pair of nodes), while the
+/// leaves are the hash of 32 bytes, so a leaf can't be mistaken for an inner
+/// node, whatever the length of `data`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `data` - The encoded leaf.
+pub fn hash_leaf(e: &Env, data: &Bytes) -> BytesN<32> {
+    let hash = e.crypto().sha256(data).to_bytes();
+    e.crypto().sha256(&hash.into()).to_bytes()
+}
+
+/// Hashes the leaf at `index` with [`hash_leaf`], the encoded leaf being
+/// `index.to_be_bytes() || data`. This is the leaf verified by
+/// [`crate::claim`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `index` - The index of the leaf.
+/// * `data` - The encoded leaf, without its index.
+pub fn hash_indexed_leaf(e: &Env, index: u32, data: &Bytes) -> BytesN<32> {
+    let mut encoded = Bytes::from_array(e, &index.to_be_bytes());
+    encoded.append(data);
+    hash_leaf(e, &encoded)
+}
+
+/// Hashes a pair of nodes with `sha256`, in sorted order. The result does not
+/// depend on the order of the arguments.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `a` - One of the nodes.
+/// * `b` - The other node.
+pub fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+
+    let mut data = Bytes::from_array(e, &first.to_array());
+    data.extend_from_array(&second.to_array());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+/// Returns the root rebuilt from `leaf` and `proof`. A leaf belongs to a tree
+/// if and only if the rebuilt root is equal to the root of the tree.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `leaf` - The hashed leaf.
+/// * `proof` - The sibling nodes from the leaf up to the root.
+pub fn process_proof(e: &Env, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut computed = leaf.clone();
+    for node in proof.iter() {
+        computed = hash_pair(e, &computed, &node);
+    }
+    computed
+}
+
+/// Returns `true` if `leaf` belongs to the tree with `root`, according to
+/// `proof`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `proof` - The sibling nodes from the leaf up to the root.
+/// * `root` - The root of the tree.
+/// * `leaf` - The hashed leaf.
+pub fn verify(e: &Env, proof: &Vec<BytesN<32>>, root: &BytesN<32>, leaf: &BytesN<32>) -> bool {
+    process_proof(e, leaf, proof) == *root
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MerkleError {
+    /// The operation failed because the root is not set.
+    RootNotSet = 140,
+    /// The operation failed because the proof does not match the root.
+    InvalidProof = 141,
+    /// The operation failed because the leaf was already claimed.
+    AlreadyClaimed = 142,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when the root is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `root` - The new root.
+///
+/// # Events
+///
+/// * topics - `["merkle_root_set"]`
+/// * data - `[root: BytesN<32>]`
+pub fn emit_root_set(e: &Env, root: &BytesN<32>) {
+    let topics = (Symbol::new(e, "merkle_root_set"),);
+    e.events().publish(topics, root.clone())
+}
@@ -0,0 +1,140 @@
+use soroban_sdk::{contracttype, panic_with_error, Bytes, BytesN, Env, Vec};
+use stellar_constants::{CLAIMED_EXTEND_AMOUNT, CLAIMED_TTL_THRESHOLD};
+
+use crate::merkle::{emit_root_set, hash_indexed_leaf, verify, MerkleError};
+
+/// Number of indices stored in a single bitmap word.
+const WORD_BITS: u32 = 128;
+
+/// Storage keys for the data associated with the Merkle utilities
+#[contracttype]
+pub enum MerkleStorageKey {
+    Root,
+    /// Bitmap word of the claimed indices, `index / 128`.
+    Claimed(u32),
+}
+
+/// Returns the stored root.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`MerkleError::RootNotSet`] - When the root is not set.
+pub fn root(e: &Env) -> BytesN<32> {
+    e.storage()
+        .instance()
+        .get(&MerkleStorageKey::Root)
+        .unwrap_or_else(|| panic_with_error!(e, MerkleError::RootNotSet))
+}
+
+/// Returns `true` if `index` was claimed.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `index` - The index of the leaf.
+pub fn is_claimed(e: &Env, index: u32) -> bool {
+    let key = MerkleStorageKey::Claimed(index / WORD_BITS);
+    if let Some(word) = e.storage().persistent().get::<_, u128>(&key) {
+        e.storage().persistent().extend_ttl(&key, CLAIMED_TTL_THRESHOLD, CLAIMED_EXTEND_AMOUNT);
+        word & (1 << (index % WORD_BITS)) != 0
+    } else {
+        false
+    }
+}
+
+/// Sets the root the leaves are verified against.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `root` - The root of the tree.
+///
+/// # Events
+///
+/// * topics - `["merkle_root_set"]`
+/// * data - `[root: BytesN<32>]`
+///
+/// # Notes
+///
+/// The claimed indices are not reset when the root changes. Use a new range
+/// of indices for the leaves of a new tree.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls. You want to
+/// invoke it most likely from the constructor, or from an admin-only
+/// function.
+pub fn set_root(e: &Env, root: &BytesN<32>) {
+    e.storage().instance().set(&MerkleStorageKey::Root, root);
+    emit_root_set(e, root);
+}
+
+/// Verifies `leaf` against the stored root.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `leaf` - The hashed leaf.
+/// * `proof` - The sibling nodes from the leaf up to the root.
+///
+/// # Errors
+///
+/// * refer to [`root`] errors.
+/// * [`MerkleError::InvalidProof`] - When `leaf` does not belong to the tree.
+pub fn verify_against_root(e: &Env, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) {
+    if !verify(e, proof, &root(e), leaf) {
+        panic_with_error!(e, MerkleError::InvalidProof);
+    }
+}
+
+/// Verifies the leaf at `index` against the stored root, and marks `index`
+/// as claimed. The leaf is computed with [`hash_indexed_leaf`], so a proof is
+/// only valid for the index it was built for: a leaf can't be claimed under
+/// another index.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `index` - The index of the leaf.
+/// * `data` - The encoded leaf, without its index.
+/// * `proof` - The sibling nodes from the leaf up to the root.
+///
+/// # Errors
+///
+/// * [`MerkleError::AlreadyClaimed`] - When `index` was already claimed.
+/// * refer to [`verify_against_root`] errors.
+///
+/// # Security Warning
+///
+/// This function lacks authorization controls. Depending on what the claim
+/// unlocks, you may want to require the authorization of the account encoded
+/// in the leaf.
+pub fn claim(e: &Env, index: u32, data: &Bytes, proof: &Vec<BytesN<32>>) {
+    if is_claimed(e, index) {
+        panic_with_error!(e, MerkleError::AlreadyClaimed);
+    }
+    verify_against_root(e, &hash_indexed_leaf(e, index, data), proof);
+    set_claimed(e, index);
+}
+
+/// Low-level function to mark `index` as claimed, without verifying any
+/// proof.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `index` - The index of the leaf.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function bypasses the proof verification. Prefer
+/// [`claim`] unless the leaf was verified separately.
+pub fn set_claimed(e: &Env, index: u32) {
+    let key = MerkleStorageKey::Claimed(index / WORD_BITS);
+    let word: u128 = e.storage().persistent().get(&key).unwrap_or(0);
+    e.storage().persistent().set(&key, &(word | (1 << (index % WORD_BITS))));
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, vec, Bytes, BytesN, Env, Vec};
+
+use crate::{
+    claim, hash_indexed_leaf, hash_leaf, hash_pair, is_claimed, process_proof, root, set_claimed,
+    set_root, verify,
+};
+
+#[contract]
+struct MockContract;
+
+/// The encoded leaf at `index`, without its index.
+fn data(e: &Env, index: u32) -> Bytes {
+    Bytes::from_array(e, &(index * 100).to_be_bytes())
+}
+
+fn leaves(e: &Env) -> Vec<BytesN<32>> {
+    let mut leaves = Vec::new(e);
+    for i in 0u32..4 {
+        leaves.push_back(hash_indexed_leaf(e, i, &data(e, i)));
+    }
+    leaves
+}
+
+/// Builds a tree of 4 leaves, and returns its root along with the proof of
+/// every leaf.
+fn tree(e: &Env) -> (BytesN<32>, Vec<Vec<BytesN<32>>>) {
+    let l = leaves(e);
+    let n01 = hash_pair(e, &l.get_unchecked(0), &l.get_unchecked(1));
+    let n23 = hash_pair(e, &l.get_unchecked(2), &l.get_unchecked(3));
+    let root = hash_pair(e, &n01, &n23);
+
+    let proofs = vec![
+        e,
+        vec![e, l.get_unchecked(1), n23.clone()],
+        vec![e, l.get_unchecked(0), n23.clone()],
+        vec![e, l.get_unchecked(3), n01.clone()],
+        vec![e, l.get_unchecked(2), n01.clone()],
+    ];
+    (root, proofs)
+}
+
+#[test]
+fn hash_pair_is_commutative() {
+    let e = Env::default();
+    let l = leaves(&e);
+
+    assert_eq!(
+        hash_pair(&e, &l.get_unchecked(0), &l.get_unchecked(1)),
+        hash_pair(&e, &l.get_unchecked(1), &l.get_unchecked(0))
+    );
+}
+
+#[test]
+fn hash_leaf_is_double_sha256() {
+    let e = Env::default();
+    let data = data(&e, 1);
+    let hash: Bytes = e.crypto().sha256(&data).to_bytes().into();
+
+    assert_eq!(hash_leaf(&e, &data), e.crypto().sha256(&hash).to_bytes());
+
+    let mut encoded = Bytes::from_array(&e, &1u32.to_be_bytes());
+    encoded.append(&data);
+    assert_eq!(hash_indexed_leaf(&e, 1, &data), hash_leaf(&e, &encoded));
+}
+
+#[test]
+fn inner_node_is_not_a_valid_leaf() {
+    let e = Env::default();
+    let l = leaves(&e);
+    let (root, _) = tree(&e);
+
+    // the preimage of an inner node, i.e. its two sorted children
+    let (a, b) = (l.get_unchecked(0), l.get_unchecked(1));
+    let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+    let mut preimage: Bytes = lo.into();
+    preimage.append(&hi.into());
+
+    let n23 = hash_pair(&e, &l.get_unchecked(2), &l.get_unchecked(3));
+    assert!(!verify(&e, &vec![&e, n23], &root, &hash_leaf(&e, &preimage)));
+}
+
+#[test]
+fn verify_works() {
+    let e = Env::default();
+    let l = leaves(&e);
+    let (root, proofs) = tree(&e);
+
+    for i in 0..4 {
+        let proof = proofs.get_unchecked(i);
+        assert_eq!(process_proof(&e, &l.get_unchecked(i), &proof), root);
+        assert!(verify(&e, &proof, &root, &l.get_unchecked(i)));
+    }
+}
+
+#[test]
+fn verify_with_wrong_proof_fails() {
+    let e = Env::default();
+    let l = leaves(&e);
+    let (root, proofs) = tree(&e);
+
+    assert!(!verify(&e, &proofs.get_unchecked(1), &root, &l.get_unchecked(0)));
+    assert!(!verify(&e, &Vec::new(&e), &root, &l.get_unchecked(0)));
+}
+
+#[test]
+fn claim_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let (tree_root, proofs) = tree(&e);
+
+    e.as_contract(&address, || {
+        set_root(&e, &tree_root);
+        assert_eq!(root(&e), tree_root);
+
+        claim(&e, 2, &data(&e, 2), &proofs.get_unchecked(2));
+
+        assert!(is_claimed(&e, 2));
+        assert!(!is_claimed(&e, 1));
+        assert!(!is_claimed(&e, 3));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #142)")]
+fn claim_twice_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let (tree_root, proofs) = tree(&e);
+
+    e.as_contract(&address, || {
+        set_root(&e, &tree_root);
+        claim(&e, 0, &data(&e, 0), &proofs.get_unchecked(0));
+        claim(&e, 0, &data(&e, 0), &proofs.get_unchecked(0));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn claim_with_invalid_proof_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let (tree_root, proofs) = tree(&e);
+
+    e.as_contract(&address, || {
+        set_root(&e, &tree_root);
+        claim(&e, 0, &data(&e, 0), &proofs.get_unchecked(3));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn claim_under_another_index_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let (tree_root, proofs) = tree(&e);
+
+    e.as_contract(&address, || {
+        set_root(&e, &tree_root);
+        // valid data and proof of leaf 2, claimed under index 3
+        claim(&e, 3, &data(&e, 2), &proofs.get_unchecked(2));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn claim_without_root_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let (_, proofs) = tree(&e);
+
+    e.as_contract(&address, || {
+        claim(&e, 0, &data(&e, 0), &proofs.get_unchecked(0));
+    });
+}
+
+#[test]
+fn claimed_bitmap_spans_multiple_words() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_claimed(&e, 127);
+        set_claimed(&e, 128);
+        set_claimed(&e, 1000);
+
+        assert!(is_claimed(&e, 127));
+        assert!(is_claimed(&e, 128));
+        assert!(is_claimed(&e, 1000));
+        assert!(!is_claimed(&e, 126));
+        assert!(!is_claimed(&e, 129));
+        assert!(!is_claimed(&e, 999));
+    });
+}
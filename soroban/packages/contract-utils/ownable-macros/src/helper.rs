@@ -0,0 +1,31 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use stellar_macro_helpers::check_env_arg;
+use syn::{parse_macro_input, ItemFn};
+
+pub fn generate_owner_check(item: TokenStream) -> TokenStream {
+    let input_fn = parse_macro_input!(item as ItemFn);
+    let (env_ident, is_ref) = check_env_arg(&input_fn);
+
+    let fn_vis = &input_fn.vis;
+    let fn_sig = &input_fn.sig;
+    let fn_block = &input_fn.block;
+    let fn_attrs = &input_fn.attrs;
+
+    let env_arg = if is_ref {
+        quote! { #env_ident }
+    } else {
+        quote! { &#env_ident }
+    };
+
+    let output = quote! {
+        #(#fn_attrs)* // retain other macros
+        #fn_vis #fn_sig {
+            stellar_ownable::enforce_owner_auth(#env_arg);
+
+            #fn_block
+        }
+    };
+
+    output.into()
+}
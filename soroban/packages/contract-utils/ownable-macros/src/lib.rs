@@ -0,0 +1,30 @@
+use proc_macro::TokenStream;
+
+use crate::helper::generate_owner_check;
+
+mod helper;
+
+/// Adds an ownership check at the beginning of the function that ensures the
+/// owner authorized the call.
+///
+/// This macro will inject a `enforce_owner_auth` check at the start of the
+/// function body. If the contract has no owner, or the owner did not
+/// authorize the invocation, the function will return early with a panic.
+///
+/// # Requirement:
+///
+/// - The first argument of the decorated function must be of type `Env` or
+///   `&Env`
+///
+/// # Example:
+///
+/// ```ignore
+/// #[only_owner]
+/// pub fn my_function(env: &Env) {
+///     // This code will only execute if the owner authorized the call
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn only_owner(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    generate_owner_check(item)
+}
@@ -0,0 +1,61 @@
+//! Ownable Contract Module.
+//!
+//! This contract module provides a basic access control mechanism, where a
+//! single account (the owner) can be granted exclusive access to specific
+//! functions.
+//!
+//! By implementing the trait [`Ownable`] for your contract, you can safely
+//! integrate the Ownable functionality. The trait [`Ownable`] has the
+//! following methods:
+//! - [`get_owner()`]
+//! - [`transfer_ownership()`]
+//! - [`accept_ownership()`]
+//! - [`renounce_ownership()`]
+//!
+//! Ownership is transferred in two steps: the current owner nominates a new
+//! owner with `transfer_ownership()`, and the transfer only completes when the
+//! nominee calls `accept_ownership()`. This prevents losing the ownership by
+//! mistakenly transferring it to an address that cannot sign.
+//!
+//! We also provide the macro `only_owner`. This macro acts as a guard for your
+//! functions. For example:
+//!
+//! ```ignore
+//! #[only_owner]
+//! fn set_config(e: &Env, config: Config) {
+//!     /* this body will execute ONLY when the owner authorized the call */
+//! }
+//! ```
+//!
+//! The owner is expected to be set once, most likely in the constructor, with
+//! [`set_owner()`]:
+//!
+//! ```ignore
+//! pub fn __constructor(e: &Env, owner: Address) {
+//!     stellar_ownable::set_owner(e, &owner);
+//! }
+//! ```
+//!
+//! You can opt-out of [`Ownable`] trait, and use `storage::*` functions
+//! directly in your contract if you want more customizability. But we
+//! encourage the use of [`Ownable`] trait instead, for the same reasons as
+//! for the `Pausable` trait: standardization, no additional cost, and better
+//! organized code.
+
+#![no_std]
+
+mod ownable;
+mod storage;
+
+pub use crate::{
+    ownable::{
+        emit_ownership_renounced, emit_ownership_transfer, emit_ownership_transfer_completed,
+        Ownable, OwnableError,
+    },
+    storage::{
+        accept_ownership, enforce_owner_auth, get_owner, pending_owner, renounce_ownership,
+        set_owner, transfer_ownership, OwnableStorageKey,
+    },
+};
+
+mod test;
@@ -0,0 +1,152 @@
+use soroban_sdk::{contracterror, Address, Env, Symbol};
+
+pub trait Ownable {
+    /// Returns the current owner, or `None` if there is no owner (never set,
+    /// or renounced).
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    fn get_owner(e: &Env) -> Option<Address> {
+        crate::get_owner(e)
+    }
+
+    /// Starts an ownership transfer by nominating `new_owner`. The transfer
+    /// completes only after `new_owner` calls `accept_ownership()`. A
+    /// previous nomination, if any, is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    /// * `new_owner` - The nominated owner.
+    ///
+    /// # Errors
+    ///
+    /// * [`OwnableError::OwnerNotSet`] - Occurs when there is no owner.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["ownership_transfer"]`
+    /// * data - `[old_owner: Address, new_owner: Address]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for the current owner is required.
+    fn transfer_ownership(e: &Env, new_owner: Address) {
+        crate::transfer_ownership(e, &new_owner);
+    }
+
+    /// Completes an ownership transfer started with `transfer_ownership()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`OwnableError::NoPendingTransfer`] - Occurs when there is no pending
+    ///   ownership transfer.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["ownership_transfer_completed"]`
+    /// * data - `[new_owner: Address]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for the nominated owner is required.
+    fn accept_ownership(e: &Env) {
+        crate::accept_ownership(e);
+    }
+
+    /// Permanently gives up the ownership, leaving the contract without an
+    /// owner. Any pending ownership transfer is cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`OwnableError::OwnerNotSet`] - Occurs when there is no owner.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["ownership_renounced"]`
+    /// * data - `[old_owner: Address]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for the current owner is required.
+    ///
+    /// **IMPORTANT**: Functions guarded with `#[only_owner]` will no longer be
+    /// callable after the ownership is renounced.
+    fn renounce_ownership(e: &Env) {
+        crate::renounce_ownership(e);
+    }
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OwnableError {
+    /// The operation failed because the contract has no owner.
+    OwnerNotSet = 120,
+    /// The operation failed because there is no pending ownership transfer.
+    NoPendingTransfer = 121,
+    /// The operation failed because the owner is already set.
+    OwnerAlreadySet = 122,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event when an ownership transfer is started.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `old_owner` - The current owner.
+/// * `new_owner` - The nominated owner.
+///
+/// # Events
+///
+/// * topics - `["ownership_transfer"]`
+/// * data - `[old_owner: Address, new_owner: Address]`
+pub fn emit_ownership_transfer(e: &Env, old_owner: &Address, new_owner: &Address) {
+    let topics = (Symbol::new(e, "ownership_transfer"),);
+    e.events().publish(topics, (old_owner, new_owner))
+}
+
+/// Emits an event when an ownership transfer is completed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `new_owner` - The new owner.
+///
+/// # Events
+///
+/// * topics - `["ownership_transfer_completed"]`
+/// * data - `[new_owner: Address]`
+pub fn emit_ownership_transfer_completed(e: &Env, new_owner: &Address) {
+    let topics = (Symbol::new(e, "ownership_transfer_completed"),);
+    e.events().publish(topics, new_owner)
+}
+
+/// Emits an event when the ownership is renounced.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `old_owner` - The owner that renounced.
+///
+/// # Events
+///
+/// * topics - `["ownership_renounced"]`
+/// * data - `[old_owner: Address]`
+pub fn emit_ownership_renounced(e: &Env, old_owner: &Address) {
+    let topics = (Symbol::new(e, "ownership_renounced"),);
+    e.events().publish(topics, old_owner)
+}
@@ -0,0 +1,156 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use crate::{
+    emit_ownership_renounced, emit_ownership_transfer, emit_ownership_transfer_completed,
+    ownable::OwnableError,
+};
+
+/// Storage keys for the data associated with `Ownable`
+#[contracttype]
+pub enum OwnableStorageKey {
+    Owner,
+    PendingOwner,
+}
+
+/// Returns the current owner, or `None` if there is no owner.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn get_owner(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&OwnableStorageKey::Owner)
+
+    // NOTE: We don't extend the TTL here, contracts usually manage the
+    // instance TTL themselves.
+}
+
+/// Returns the nominated owner of a pending ownership transfer, or `None` if
+/// there is no pending transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+pub fn pending_owner(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&OwnableStorageKey::PendingOwner)
+}
+
+/// Sets the initial owner.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The owner.
+///
+/// # Errors
+///
+/// * [`OwnableError::OwnerAlreadySet`] - Occurs when the owner is already
+///   set.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls. You want to
+/// invoke it most likely from the constructor.
+pub fn set_owner(e: &Env, owner: &Address) {
+    if e.storage().instance().has(&OwnableStorageKey::Owner) {
+        panic_with_error!(e, OwnableError::OwnerAlreadySet);
+    }
+    e.storage().instance().set(&OwnableStorageKey::Owner, owner);
+}
+
+/// Starts an ownership transfer by nominating `new_owner`.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `new_owner` - The nominated owner.
+///
+/// # Errors
+///
+/// * refer to [`enforce_owner_auth`] errors.
+///
+/// # Events
+///
+/// * topics - `["ownership_transfer"]`
+/// * data - `[old_owner: Address, new_owner: Address]`
+///
+/// # Notes
+///
+/// Authorization for the current owner is required.
+pub fn transfer_ownership(e: &Env, new_owner: &Address) {
+    let owner = enforce_owner_auth(e);
+    e.storage().instance().set(&OwnableStorageKey::PendingOwner, new_owner);
+    emit_ownership_transfer(e, &owner, new_owner);
+}
+
+/// Completes a pending ownership transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+///
+/// # Errors
+///
+/// * [`OwnableError::NoPendingTransfer`] - Occurs when there is no pending
+///   ownership transfer.
+///
+/// # Events
+///
+/// * topics - `["ownership_transfer_completed"]`
+/// * data - `[new_owner: Address]`
+///
+/// # Notes
+///
+/// Authorization for the nominated owner is required.
+pub fn accept_ownership(e: &Env) {
+    let Some(new_owner) = pending_owner(e) else {
+        panic_with_error!(e, OwnableError::NoPendingTransfer);
+    };
+    new_owner.require_auth();
+
+    e.storage().instance().remove(&OwnableStorageKey::PendingOwner);
+    e.storage().instance().set(&OwnableStorageKey::Owner, &new_owner);
+    emit_ownership_transfer_completed(e, &new_owner);
+}
+
+/// Removes the owner, and cancels any pending ownership transfer.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+///
+/// # Errors
+///
+/// * refer to [`enforce_owner_auth`] errors.
+///
+/// # Events
+///
+/// * topics - `["ownership_renounced"]`
+/// * data - `[old_owner: Address]`
+///
+/// # Notes
+///
+/// Authorization for the current owner is required.
+pub fn renounce_ownership(e: &Env) {
+    let owner = enforce_owner_auth(e);
+    e.storage().instance().remove(&OwnableStorageKey::PendingOwner);
+    e.storage().instance().remove(&OwnableStorageKey::Owner);
+    emit_ownership_renounced(e, &owner);
+}
+
+/// Helper to make a function callable only by the owner. Requires the
+/// authorization of the owner and returns it.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+///
+/// # Errors
+///
+/// * [`OwnableError::OwnerNotSet`] - Occurs when there is no owner.
+pub fn enforce_owner_auth(e: &Env) -> Address {
+    let Some(owner) = get_owner(e) else {
+        panic_with_error!(e, OwnableError::OwnerNotSet);
+    };
+    owner.require_auth();
+    owner
+}
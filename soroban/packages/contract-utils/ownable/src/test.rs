@@ -0,0 +1,209 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::storage::{
+    accept_ownership, enforce_owner_auth, get_owner, pending_owner, renounce_ownership, set_owner,
+    transfer_ownership,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn initial_state() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        assert_eq!(get_owner(&e), None);
+        assert_eq!(pending_owner(&e), None);
+    });
+}
+
+#[test]
+fn set_owner_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+        assert_eq!(get_owner(&e), Some(owner));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #122)")]
+fn errors_set_owner_when_already_set() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+        set_owner(&e, &owner);
+    });
+}
+
+#[test]
+fn transfer_ownership_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+
+        transfer_ownership(&e, &new_owner);
+
+        // the ownership does not change until accepted
+        assert_eq!(get_owner(&e), Some(owner.clone()));
+        assert_eq!(pending_owner(&e), Some(new_owner.clone()));
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![&e, Symbol::new(&e, "ownership_transfer").into_val(&e)],
+                    (owner.clone(), new_owner.clone()).into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+fn accept_ownership_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+        transfer_ownership(&e, &new_owner);
+    });
+
+    e.as_contract(&address, || {
+        accept_ownership(&e);
+
+        assert_eq!(get_owner(&e), Some(new_owner.clone()));
+        assert_eq!(pending_owner(&e), None);
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![&e, Symbol::new(&e, "ownership_transfer_completed").into_val(&e)],
+                    new_owner.into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #121)")]
+fn errors_accept_ownership_without_pending_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+        accept_ownership(&e);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn errors_transfer_ownership_without_owner() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let new_owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        transfer_ownership(&e, &new_owner);
+    });
+}
+
+#[test]
+fn renounce_ownership_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+        transfer_ownership(&e, &new_owner);
+    });
+
+    e.as_contract(&address, || {
+        renounce_ownership(&e);
+
+        assert_eq!(get_owner(&e), None);
+        // pending transfer is cancelled
+        assert_eq!(pending_owner(&e), None);
+
+        let events = e.events().all();
+        assert_eq!(
+            events,
+            vec![
+                &e,
+                (
+                    address.clone(),
+                    vec![&e, Symbol::new(&e, "ownership_renounced").into_val(&e)],
+                    owner.into_val(&e)
+                )
+            ]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #120)")]
+fn errors_enforce_owner_auth_after_renounce() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+        renounce_ownership(&e);
+        enforce_owner_auth(&e);
+    });
+}
+
+#[test]
+fn enforce_owner_auth_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_owner(&e, &owner);
+        assert_eq!(enforce_owner_auth(&e), owner);
+    });
+}
@@ -283,6 +283,34 @@ impl<'a> EventAssertion<'a> {
         assert_eq!(event_data.1, live_until_ledger, "Approve event has wrong live_until_ledger");
     }
 
+    pub fn assert_non_fungible_batch_transfer(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        let event = self.find_event_by_symbol("batch_transfer");
+
+        assert!(event.is_some(), "BatchTransfer event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 3, "BatchTransfer event should have 3 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "batch_transfer"));
+
+        let event_from: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_to: Address = topics.get_unchecked(2).into_val(self.env);
+        let event_token_ids: Vec<TokenId> = data.into_val(self.env);
+
+        assert_eq!(&event_from, from, "BatchTransfer event has wrong from address");
+        assert_eq!(&event_to, to, "BatchTransfer event has wrong to address");
+        assert_eq!(&event_token_ids, token_ids, "BatchTransfer event has wrong token_ids");
+    }
+
     pub fn assert_consecutive_mint(&mut self, to: &Address, from_id: TokenId, to_id: TokenId) {
         let event = self.find_event_by_symbol("consecutive_mint");
 
@@ -304,4 +332,337 @@ impl<'a> EventAssertion<'a> {
         assert_eq!(event_data.0, from_id, "ConsecutiveMint event has wrong from_token_id");
         assert_eq!(event_data.1, to_id, "ConsecutiveMint event has wrong to_token_id");
     }
+
+    pub fn assert_consecutive_mints(&mut self, mints: &[(&Address, TokenId, TokenId)]) {
+        for (to, from_id, to_id) in mints {
+            self.assert_consecutive_mint(to, *from_id, *to_id);
+        }
+    }
+
+    pub fn assert_set_default_royalty(&mut self, receiver: &Address, basis_points: u32) {
+        let event = self.find_event_by_symbol("set_default_royalty");
+
+        assert!(event.is_some(), "SetDefaultRoyalty event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "SetDefaultRoyalty event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "set_default_royalty"));
+
+        let event_receiver: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_basis_points: u32 = data.into_val(self.env);
+
+        assert_eq!(&event_receiver, receiver, "SetDefaultRoyalty event has wrong receiver");
+        assert_eq!(
+            event_basis_points, basis_points,
+            "SetDefaultRoyalty event has wrong basis_points"
+        );
+    }
+
+    pub fn assert_set_token_royalty(
+        &mut self,
+        receiver: &Address,
+        token_id: TokenId,
+        basis_points: u32,
+    ) {
+        let event = self.find_event_by_symbol("set_token_royalty");
+
+        assert!(event.is_some(), "SetTokenRoyalty event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 3, "SetTokenRoyalty event should have 3 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "set_token_royalty"));
+
+        let event_receiver: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_token_id: TokenId = topics.get_unchecked(2).into_val(self.env);
+        let event_basis_points: u32 = data.into_val(self.env);
+
+        assert_eq!(&event_receiver, receiver, "SetTokenRoyalty event has wrong receiver");
+        assert_eq!(event_token_id, token_id, "SetTokenRoyalty event has wrong token_id");
+        assert_eq!(
+            event_basis_points, basis_points,
+            "SetTokenRoyalty event has wrong basis_points"
+        );
+    }
+
+    pub fn assert_remove_token_royalty(&mut self, token_id: TokenId) {
+        let event = self.find_event_by_symbol("remove_token_royalty");
+
+        assert!(event.is_some(), "RemoveTokenRoyalty event not found in event log");
+
+        let (contract, topics, _data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "RemoveTokenRoyalty event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "remove_token_royalty"));
+
+        let event_token_id: TokenId = topics.get_unchecked(1).into_val(self.env);
+        assert_eq!(event_token_id, token_id, "RemoveTokenRoyalty event has wrong token_id");
+    }
+
+    pub fn assert_metadata_update(&mut self, token_id: TokenId) {
+        let event = self.find_event_by_symbol("metadata_update");
+
+        assert!(event.is_some(), "MetadataUpdate event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 1, "MetadataUpdate event should have 1 topic");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "metadata_update"));
+
+        let event_token_id: TokenId = data.into_val(self.env);
+        assert_eq!(event_token_id, token_id, "MetadataUpdate event has wrong token_id");
+    }
+
+    pub fn assert_batch_metadata_update(&mut self, from_id: TokenId, to_id: TokenId) {
+        let event = self.find_event_by_symbol("batch_metadata_update");
+
+        assert!(event.is_some(), "BatchMetadataUpdate event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 1, "BatchMetadataUpdate event should have 1 topic");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "batch_metadata_update"));
+
+        let event_data: (TokenId, TokenId) = data.into_val(self.env);
+        assert_eq!(event_data.0, from_id, "BatchMetadataUpdate event has wrong from_token_id");
+        assert_eq!(event_data.1, to_id, "BatchMetadataUpdate event has wrong to_token_id");
+    }
+
+    pub fn assert_update_user(&mut self, token_id: TokenId, user: &Address, expires_ledger: u32) {
+        let event = self.find_event_by_symbol("update_user");
+
+        assert!(event.is_some(), "UpdateUser event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "UpdateUser event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "update_user"));
+
+        let event_token_id: TokenId = topics.get_unchecked(1).into_val(self.env);
+        let event_data: (Address, u32) = data.into_val(self.env);
+
+        assert_eq!(event_token_id, token_id, "UpdateUser event has wrong token_id");
+        assert_eq!(&event_data.0, user, "UpdateUser event has wrong user");
+        assert_eq!(event_data.1, expires_ledger, "UpdateUser event has wrong expires_ledger");
+    }
+
+    pub fn assert_remove_user(&mut self, token_id: TokenId) {
+        let event = self.find_event_by_symbol("remove_user");
+
+        assert!(event.is_some(), "RemoveUser event not found in event log");
+
+        let (contract, topics, _data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "RemoveUser event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "remove_user"));
+
+        let event_token_id: TokenId = topics.get_unchecked(1).into_val(self.env);
+        assert_eq!(event_token_id, token_id, "RemoveUser event has wrong token_id");
+    }
+
+    pub fn assert_multi_token_transfer(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        id: TokenId,
+        amount: i128,
+    ) {
+        let transfer_event = self.find_event_by_symbol("transfer");
+
+        assert!(transfer_event.is_some(), "Transfer event not found in event log");
+
+        let (contract, topics, data) = transfer_event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 3, "Transfer event should have 3 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, symbol_short!("transfer"));
+
+        let event_from: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_to: Address = topics.get_unchecked(2).into_val(self.env);
+        let event_data: (TokenId, i128) = data.into_val(self.env);
+
+        assert_eq!(&event_from, from, "Transfer event has wrong from address");
+        assert_eq!(&event_to, to, "Transfer event has wrong to address");
+        assert_eq!(event_data.0, id, "Transfer event has wrong id");
+        assert_eq!(event_data.1, amount, "Transfer event has wrong amount");
+    }
+
+    pub fn assert_multi_token_batch_transfer(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        ids: &Vec<TokenId>,
+        amounts: &Vec<i128>,
+    ) {
+        let transfer_event = self.find_event_by_symbol("batch_transfer");
+
+        assert!(transfer_event.is_some(), "BatchTransfer event not found in event log");
+
+        let (contract, topics, data) = transfer_event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 3, "BatchTransfer event should have 3 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "batch_transfer"));
+
+        let event_from: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_to: Address = topics.get_unchecked(2).into_val(self.env);
+        let event_data: (Vec<TokenId>, Vec<i128>) = data.into_val(self.env);
+
+        assert_eq!(&event_from, from, "BatchTransfer event has wrong from address");
+        assert_eq!(&event_to, to, "BatchTransfer event has wrong to address");
+        assert_eq!(&event_data.0, ids, "BatchTransfer event has wrong ids");
+        assert_eq!(&event_data.1, amounts, "BatchTransfer event has wrong amounts");
+    }
+
+    pub fn assert_multi_token_mint(&mut self, to: &Address, id: TokenId, amount: i128) {
+        let mint_event = self.find_event_by_symbol("mint");
+
+        assert!(mint_event.is_some(), "Mint event not found in event log");
+
+        let (contract, topics, data) = mint_event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "Mint event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, symbol_short!("mint"));
+
+        let event_to: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_data: (TokenId, i128) = data.into_val(self.env);
+
+        assert_eq!(&event_to, to, "Mint event has wrong to address");
+        assert_eq!(event_data.0, id, "Mint event has wrong id");
+        assert_eq!(event_data.1, amount, "Mint event has wrong amount");
+    }
+
+    pub fn assert_multi_token_batch_mint(
+        &mut self,
+        to: &Address,
+        ids: &Vec<TokenId>,
+        amounts: &Vec<i128>,
+    ) {
+        let mint_event = self.find_event_by_symbol("batch_mint");
+
+        assert!(mint_event.is_some(), "BatchMint event not found in event log");
+
+        let (contract, topics, data) = mint_event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "BatchMint event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "batch_mint"));
+
+        let event_to: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_data: (Vec<TokenId>, Vec<i128>) = data.into_val(self.env);
+
+        assert_eq!(&event_to, to, "BatchMint event has wrong to address");
+        assert_eq!(&event_data.0, ids, "BatchMint event has wrong ids");
+        assert_eq!(&event_data.1, amounts, "BatchMint event has wrong amounts");
+    }
+
+    pub fn assert_multi_token_burn(&mut self, from: &Address, id: TokenId, amount: i128) {
+        let burn_event = self.find_event_by_symbol("burn");
+
+        assert!(burn_event.is_some(), "Burn event not found in event log");
+
+        let (contract, topics, data) = burn_event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "Burn event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, symbol_short!("burn"));
+
+        let event_from: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_data: (TokenId, i128) = data.into_val(self.env);
+
+        assert_eq!(&event_from, from, "Burn event has wrong from address");
+        assert_eq!(event_data.0, id, "Burn event has wrong id");
+        assert_eq!(event_data.1, amount, "Burn event has wrong amount");
+    }
+
+    pub fn assert_multi_token_batch_burn(
+        &mut self,
+        from: &Address,
+        ids: &Vec<TokenId>,
+        amounts: &Vec<i128>,
+    ) {
+        let burn_event = self.find_event_by_symbol("batch_burn");
+
+        assert!(burn_event.is_some(), "BatchBurn event not found in event log");
+
+        let (contract, topics, data) = burn_event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 2, "BatchBurn event should have 2 topics");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "batch_burn"));
+
+        let event_from: Address = topics.get_unchecked(1).into_val(self.env);
+        let event_data: (Vec<TokenId>, Vec<i128>) = data.into_val(self.env);
+
+        assert_eq!(&event_from, from, "BatchBurn event has wrong from address");
+        assert_eq!(&event_data.0, ids, "BatchBurn event has wrong ids");
+        assert_eq!(&event_data.1, amounts, "BatchBurn event has wrong amounts");
+    }
+
+    pub fn assert_reveal(&mut self, collection_size: TokenId, offset: TokenId) {
+        let event = self.find_event_by_symbol("reveal");
+
+        assert!(event.is_some(), "Reveal event not found in event log");
+
+        let (contract, topics, data) = event.unwrap();
+        assert_eq!(contract, self.contract, "Event from wrong contract");
+
+        let topics: Vec<Val> = topics.clone();
+        assert_eq!(topics.len(), 1, "Reveal event should have 1 topic");
+
+        let topic_symbol: Symbol = topics.get_unchecked(0).into_val(self.env);
+        assert_eq!(topic_symbol, Symbol::new(self.env, "reveal"));
+
+        let event_data: (TokenId, TokenId) = data.into_val(self.env);
+        assert_eq!(event_data.0, collection_size, "Reveal event has wrong collection_size");
+        assert_eq!(event_data.1, offset, "Reveal event has wrong offset");
+    }
 }
@@ -0,0 +1,174 @@
+mod storage;
+pub use self::storage::{batch_burn, batch_burn_from, burn, burn_from};
+
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::TokenId;
+
+/// Burnable Trait for Multi Token
+///
+/// The `MultiTokenBurnable` trait extends the `MultiToken` trait to provide
+/// the capability to burn tokens. This trait is designed to be used in
+/// conjunction with the `MultiToken` trait.
+///
+/// Excluding the `burn` functionality from the [`crate::MultiToken`] trait is
+/// a deliberate design choice to accommodate flexibility and customization
+/// for various smart contract use cases.
+pub trait MultiTokenBurnable {
+    /// Destroys `amount` tokens of type `id` from `from`. Updates the total
+    /// supply of `id` accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `id` - The token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   burn more tokens than `from` current balance.
+    /// * [`crate::MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[id: TokenId, amount: i128]`
+    fn burn(e: &Env, from: Address, id: TokenId, amount: i128) {
+        crate::burnable::burn(e, &from, id, amount);
+    }
+
+    /// Destroys `amount` tokens of type `id` from `from`, by an operator
+    /// approved by `from`. Updates the total supply of `id` accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn, must be `from` or an
+    ///   operator approved by `from`.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `id` - The token type.
+    /// * `amount` - The amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::InsufficientApproval`] - When `spender` is
+    ///   not allowed to manage the tokens of `from`.
+    /// * [`crate::MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   burn more tokens than `from` current balance.
+    /// * [`crate::MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[id: TokenId, amount: i128]`
+    fn burn_from(e: &Env, spender: Address, from: Address, id: TokenId, amount: i128) {
+        crate::burnable::burn_from(e, &spender, &from, id, amount);
+    }
+
+    /// Destroys `amounts[i]` tokens of type `ids[i]` from `from`, for every
+    /// index `i`. Updates the total supplies accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `ids` - The token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::LengthMismatch`] - When `ids` and `amounts`
+    ///   have different lengths.
+    /// * [`crate::MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   burn more tokens than `from` current balance.
+    /// * [`crate::MultiTokenError::LessThanZero`] - When an amount is
+    ///   negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_burn", from: Address]`
+    /// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+    fn batch_burn(e: &Env, from: Address, ids: Vec<TokenId>, amounts: Vec<i128>) {
+        crate::burnable::batch_burn(e, &from, &ids, &amounts);
+    }
+
+    /// Destroys `amounts[i]` tokens of type `ids[i]` from `from`, for every
+    /// index `i`, by an operator approved by `from`. Updates the total
+    /// supplies accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the burn, must be `from` or an
+    ///   operator approved by `from`.
+    /// * `from` - The account whose tokens are destroyed.
+    /// * `ids` - The token types.
+    /// * `amounts` - The amounts of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::InsufficientApproval`] - When `spender` is
+    ///   not allowed to manage the tokens of `from`.
+    /// * [`crate::MultiTokenError::LengthMismatch`] - When `ids` and `amounts`
+    ///   have different lengths.
+    /// * [`crate::MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   burn more tokens than `from` current balance.
+    /// * [`crate::MultiTokenError::LessThanZero`] - When an amount is
+    ///   negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_burn", from: Address]`
+    /// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+    fn batch_burn_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        ids: Vec<TokenId>,
+        amounts: Vec<i128>,
+    ) {
+        crate::burnable::batch_burn_from(e, &spender, &from, &ids, &amounts);
+    }
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a burn of tokens of a single type.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to be burned.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+pub fn emit_burn(e: &Env, from: &Address, id: TokenId, amount: i128) {
+    let topics = (symbol_short!("burn"), from);
+    e.events().publish(topics, (id, amount))
+}
+
+/// Emits an event indicating a burn of tokens of multiple types.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to be burned.
+///
+/// # Events
+///
+/// * topics - `["batch_burn", from: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+pub fn emit_batch_burn(e: &Env, from: &Address, ids: &Vec<TokenId>, amounts: &Vec<i128>) {
+    let topics = (Symbol::new(e, "batch_burn"), from);
+    e.events().publish(topics, (ids.clone(), amounts.clone()))
+}
@@ -0,0 +1,132 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    extensions::burnable::{emit_batch_burn, emit_burn},
+    storage::{batch_update, check_operator, update},
+    TokenId,
+};
+
+/// Destroys `amount` tokens of type `id` from `from`. Updates the total
+/// supply of `id` accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The account whose tokens are destroyed.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to burn.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn burn(e: &Env, from: &Address, id: TokenId, amount: i128) {
+    from.require_auth();
+    update(e, Some(from), None, id, amount);
+    emit_burn(e, from, id, amount);
+}
+
+/// Destroys `amount` tokens of type `id` from `from`, by `from` or an
+/// operator approved by `from`. Updates the total supply of `id` accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `spender` - The address authorizing the burn.
+/// * `from` - The account whose tokens are destroyed.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to burn.
+///
+/// # Errors
+///
+/// * refer to [`check_operator`] errors.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["burn", from: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `spender` is required.
+pub fn burn_from(e: &Env, spender: &Address, from: &Address, id: TokenId, amount: i128) {
+    spender.require_auth();
+    check_operator(e, spender, from);
+    update(e, Some(from), None, id, amount);
+    emit_burn(e, from, id, amount);
+}
+
+/// Destroys `amounts[i]` tokens of type `ids[i]` from `from`, for every index
+/// `i`. Updates the total supplies accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The account whose tokens are destroyed.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to burn.
+///
+/// # Errors
+///
+/// * refer to [`batch_update`] errors.
+///
+/// # Events
+///
+/// * topics - `["batch_burn", from: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn batch_burn(e: &Env, from: &Address, ids: &Vec<TokenId>, amounts: &Vec<i128>) {
+    from.require_auth();
+    batch_update(e, Some(from), None, ids, amounts);
+    emit_batch_burn(e, from, ids, amounts);
+}
+
+/// Destroys `amounts[i]` tokens of type `ids[i]` from `from`, for every index
+/// `i`, by `from` or an operator approved by `from`. Updates the total
+/// supplies accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `spender` - The address authorizing the burn.
+/// * `from` - The account whose tokens are destroyed.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to burn.
+///
+/// # Errors
+///
+/// * refer to [`check_operator`] errors.
+/// * refer to [`batch_update`] errors.
+///
+/// # Events
+///
+/// * topics - `["batch_burn", from: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+///
+/// # Notes
+///
+/// Authorization for `spender` is required.
+pub fn batch_burn_from(
+    e: &Env,
+    spender: &Address,
+    from: &Address,
+    ids: &Vec<TokenId>,
+    amounts: &Vec<i128>,
+) {
+    spender.require_auth();
+    check_operator(e, spender, from);
+    batch_update(e, Some(from), None, ids, amounts);
+    emit_batch_burn(e, from, ids, amounts);
+}
@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::{
+        burnable::{batch_burn, batch_burn_from, burn, burn_from},
+        mintable::mint,
+    },
+    storage::{approve_for_all, balance_of, total_supply},
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn burn_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 1, 100);
+        burn(&e, &account, 1, 40);
+
+        assert_eq!(balance_of(&e, &account, 1), 60);
+        assert_eq!(total_supply(&e, 1), 60);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_multi_token_mint(&account, 1, 100);
+        event_assert.assert_multi_token_burn(&account, 1, 40);
+    });
+}
+
+#[test]
+fn burn_from_by_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1, 100);
+        approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        burn_from(&e, &operator, &owner, 1, 100);
+
+        assert_eq!(balance_of(&e, &owner, 1), 0);
+        assert_eq!(total_supply(&e, 1), 0);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_multi_token_burn(&owner, 1, 100);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn burn_from_without_approval_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1, 100);
+        burn_from(&e, &spender, &owner, 1, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #400)")]
+fn burn_insufficient_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 1, 100);
+        burn(&e, &account, 1, 101);
+    });
+}
+
+#[test]
+fn batch_burn_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 1, 100);
+        mint(&e, &account, 2, 10);
+
+        let ids = vec![&e, 1, 2];
+        let amounts = vec![&e, 50, 10];
+        batch_burn(&e, &account, &ids, &amounts);
+
+        assert_eq!(balance_of(&e, &account, 1), 50);
+        assert_eq!(balance_of(&e, &account, 2), 0);
+        assert_eq!(total_supply(&e, 1), 50);
+        assert_eq!(total_supply(&e, 2), 0);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_multi_token_batch_burn(&account, &ids, &amounts);
+    });
+}
+
+#[test]
+fn batch_burn_from_by_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1, 100);
+        mint(&e, &owner, 2, 10);
+        approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        batch_burn_from(&e, &operator, &owner, &vec![&e, 1, 2], &vec![&e, 100, 5]);
+
+        assert_eq!(balance_of(&e, &owner, 1), 0);
+        assert_eq!(balance_of(&e, &owner, 2), 5);
+        assert_eq!(total_supply(&e, 2), 5);
+    });
+}
@@ -0,0 +1,139 @@
+mod storage;
+pub use self::storage::{batch_mint, mint};
+
+mod test;
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::TokenId;
+
+/// Mintable Trait for Multi Token
+///
+/// The `MultiTokenMintable` trait extends the `MultiToken` trait to provide
+/// the capability to mint tokens. This trait is designed to be used in
+/// conjunction with the `MultiToken` trait.
+///
+/// Excluding the `mint` functionality from the [`crate::MultiToken`] trait is
+/// a deliberate design choice to accommodate flexibility and customization
+/// for various smart contract use cases.
+pub trait MultiTokenMintable {
+    /// Creates `amount` tokens of type `id` and assigns them to `to`. Updates
+    /// the total supply of `id` accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `id` - The token type.
+    /// * `amount` - The amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::LessThanZero`] - When `amount < 0`.
+    /// * [`crate::MultiTokenError::MathOverflow`] - When the total supply of
+    ///   `id` overflows.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[id: TokenId, amount: i128]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`crate::mintable::mint()`] when implementing this
+    /// function.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The base implementation of mint() intentionally lacks
+    /// authorization controls. You MUST implement proper authorization in
+    /// your contract. For example:
+    ///
+    /// ```rust
+    /// fn mint(e: &Env, to: Address, id: TokenId, amount: i128) {
+    ///     // 1. Verify admin has minting privileges (optional)
+    ///     let admin = e.storage().instance().get(&ADMIN_KEY).unwrap();
+    ///     admin.require_auth();
+    ///
+    ///     // 2. Only then call the actual mint function
+    ///     crate::mintable::mint(e, &to, id, amount);
+    /// }
+    /// ```
+    ///
+    /// Failing to add proper authorization could allow anyone to mint tokens!
+    fn mint(e: &Env, to: Address, id: TokenId, amount: i128);
+
+    /// Creates `amounts[i]` tokens of type `ids[i]` for every index `i`, and
+    /// assigns them to `to`. Updates the total supplies accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new tokens.
+    /// * `ids` - The token types.
+    /// * `amounts` - The amounts of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::MultiTokenError::LengthMismatch`] - When `ids` and `amounts`
+    ///   have different lengths.
+    /// * [`crate::MultiTokenError::LessThanZero`] - When an amount is
+    ///   negative.
+    /// * [`crate::MultiTokenError::MathOverflow`] - When a total supply
+    ///   overflows.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_mint", to: Address]`
+    /// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+    ///
+    /// # Notes
+    ///
+    /// We recommend using [`crate::mintable::batch_mint()`] when implementing
+    /// this function.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The base implementation of batch_mint() intentionally
+    /// lacks authorization controls. You MUST implement proper authorization
+    /// in your contract, as for [`MultiTokenMintable::mint`].
+    fn batch_mint(e: &Env, to: Address, ids: Vec<TokenId>, amounts: Vec<i128>);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a mint of tokens of a single type.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to mint.
+///
+/// # Events
+///
+/// * topics - `["mint", to: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+pub fn emit_mint(e: &Env, to: &Address, id: TokenId, amount: i128) {
+    let topics = (symbol_short!("mint"), to);
+    e.events().publish(topics, (id, amount))
+}
+
+/// Emits an event indicating a mint of tokens of multiple types.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to mint.
+///
+/// # Events
+///
+/// * topics - `["batch_mint", to: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+pub fn emit_batch_mint(e: &Env, to: &Address, ids: &Vec<TokenId>, amounts: &Vec<i128>) {
+    let topics = (Symbol::new(e, "batch_mint"), to);
+    e.events().publish(topics, (ids.clone(), amounts.clone()))
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    extensions::mintable::{emit_batch_mint, emit_mint},
+    storage::{batch_update, update},
+    TokenId,
+};
+
+/// Creates `amount` tokens of type `id` and assigns them to `to`. Updates
+/// the total supply of `id` accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to mint.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["mint", to: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute minting
+/// operations. Failure to implement proper authorization could lead to
+/// security vulnerabilities and unauthorized token creation.
+///
+/// You probably want to do something like this (pseudo-code):
+///
+/// ```ignore
+/// let admin = read_administrator(e);
+/// admin.require_auth();
+/// ```
+pub fn mint(e: &Env, to: &Address, id: TokenId, amount: i128) {
+    update(e, None, Some(to), id, amount);
+    emit_mint(e, to, id, amount);
+}
+
+/// Creates `amounts[i]` tokens of type `ids[i]` for every index `i`, and
+/// assigns them to `to`. Updates the total supplies accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The address receiving the new tokens.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to mint.
+///
+/// # Errors
+///
+/// * refer to [`batch_update`] errors.
+///
+/// # Events
+///
+/// * topics - `["batch_mint", to: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+///
+/// # Security Warning
+///
+/// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+///
+/// It is the responsibility of the implementer to establish appropriate access
+/// controls to ensure that only authorized accounts can execute minting
+/// operations. Failure to implement proper authorization could lead to
+/// security vulnerabilities and unauthorized token creation.
+pub fn batch_mint(e: &Env, to: &Address, ids: &Vec<TokenId>, amounts: &Vec<i128>) {
+    batch_update(e, None, Some(to), ids, amounts);
+    emit_batch_mint(e, to, ids, amounts);
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    extensions::mintable::{batch_mint, mint},
+    storage::{balance_of, total_supply},
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn mint_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 7, 100);
+
+        assert_eq!(balance_of(&e, &account, 7), 100);
+        assert_eq!(total_supply(&e, 7), 100);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_multi_token_mint(&account, 7, 100);
+    });
+}
+
+#[test]
+fn batch_mint_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let ids = vec![&e, 1, 2, 1];
+        let amounts = vec![&e, 10, 1, 5];
+        batch_mint(&e, &account, &ids, &amounts);
+
+        assert_eq!(balance_of(&e, &account, 1), 15);
+        assert_eq!(balance_of(&e, &account, 2), 1);
+        assert_eq!(total_supply(&e, 1), 15);
+        assert_eq!(total_supply(&e, 2), 1);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_multi_token_batch_mint(&account, &ids, &amounts);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #404)")]
+fn mint_total_supply_overflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 1, i128::MAX);
+        mint(&e, &account, 1, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #405)")]
+fn batch_mint_length_mismatch_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        batch_mint(&e, &account, &vec![&e, 1, 2], &vec![&e, 10]);
+    });
+}
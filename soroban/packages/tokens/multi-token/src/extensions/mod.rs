@@ -0,0 +1,2 @@
+pub mod burnable;
+pub mod mintable;
@@ -0,0 +1,82 @@
+//! # Multi Token Contract Module.
+//!
+//! Implements utilities for handling multi tokens in a Soroban contract.
+//!
+//! A single multi token contract manages any number of token types, each
+//! identified by a [`TokenId`]. Every token type can have many copies (like a
+//! fungible token), or a single one (like a non-fungible token), which makes
+//! this module well suited for semi-fungible items, e.g. game assets.
+//!
+//! ## Design Overview
+//!
+//! This module is structured to provide flexibility to developers by splitting
+//! functionalities into higher-level and lower-level operations:
+//!
+//! - **High-Level Functions**: These include all necessary checks,
+//!   verifications, authorizations, state-changing logic, and event emissions.
+//!   They simplify usage by handling core logic securely. Users can directly
+//!   call these functions for typical token operations without worrying about
+//!   implementation details.
+//!
+//! - **Low-Level Functions**: These offer granular control for developers who
+//!   need to compose their own workflows. Such functions expose internal
+//!   mechanisms and require the caller to handle verifications and
+//!   authorizations manually.
+//!
+//! By offering this dual-layered approach, developers can choose between
+//! convenience and customization, depending on their project requirements.
+//!
+//! ## Structure
+//!
+//! The base module includes:
+//!
+//! - Balances and total supply per token id
+//! - Single and batch transfers
+//! - Operator approvals
+//! - URI management (`uri`), composed from a base URI and the token id
+//!
+//! The following optional extensions are available:
+//!
+//! - Mintable: Allows creation of new tokens that increases the total supply
+//!   of a token id.
+//! - Burnable: Enables token holders to destroy their tokens, reducing the
+//!   total supply of a token id.
+//!
+//! ## Compatibility and Compliance
+//!
+//! The ERC-1155 interface is adapted to Stellar Ecosystem, with the following
+//! differences:
+//!
+//! - `transfer()` and `batch_transfer()` functions are made available for
+//!   consistency with the Fungible and Non-Fungible Token interfaces, next to
+//!   their operator variants `transfer_from()` and `batch_transfer_from()`.
+//! - Operator approvals expire at `live_until_ledger`, similarly to
+//!   `approve_for_all()` of the Non-Fungible Token module.
+//! - The `onERC1155Received` acceptance check is not performed.
+//!
+//! ## Notes for Developers
+//!
+//! - **Security Considerations**: While high-level functions handle necessary
+//!   checks, users of low-level functions must take extra care to ensure
+//!   correctness and security.
+//! - **TTL management**: This library handles the TTL of only `temporary` and
+//!   `persistent` storage entries declared by the library. The `instance` TTL
+//!   management is left to the implementor due to flexibility.
+#![no_std]
+
+mod extensions;
+mod multi_token;
+mod storage;
+
+pub use extensions::{burnable, mintable};
+pub use multi_token::{
+    emit_approve_for_all, emit_batch_transfer, emit_transfer, MultiToken, MultiTokenError,
+};
+pub use stellar_non_fungible::{ApprovalForAllData, TokenId};
+pub use storage::{
+    approve_for_all, balance_of, balance_of_batch, base_uri, batch_transfer, batch_transfer_from,
+    batch_update, check_operator, is_approved_for_all, set_base_uri, total_supply, transfer,
+    transfer_from, update, uri, BalanceKey, StorageKey,
+};
+
+mod test;
@@ -0,0 +1,317 @@
+use soroban_sdk::{contracterror, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::TokenId;
+
+/// Vanilla Multi Token Trait
+///
+/// The `MultiToken` trait defines the core functionality for multi tokens,
+/// mirroring ERC-1155. It provides a standard interface for managing balances
+/// of many token types, transfers and operator approvals.
+pub trait MultiToken {
+    /// Returns the amount of tokens of type `id` held by `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `account` - The address for which the balance is being queried.
+    /// * `id` - The token type.
+    fn balance_of(e: &Env, account: Address, id: TokenId) -> i128 {
+        crate::balance_of(e, &account, id)
+    }
+
+    /// Returns the balances of multiple `(account, id)` pairs, where the
+    /// balance at index `i` is the amount of tokens of type `ids[i]` held by
+    /// `accounts[i]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `accounts` - The addresses for which the balances are being queried.
+    /// * `ids` - The token types.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `accounts` and `ids` have
+    ///   different lengths.
+    fn balance_of_batch(e: &Env, accounts: Vec<Address>, ids: Vec<TokenId>) -> Vec<i128> {
+        crate::balance_of_batch(e, &accounts, &ids)
+    }
+
+    /// Returns the amount of tokens of type `id` in circulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `id` - The token type.
+    fn total_supply(e: &Env, id: TokenId) -> i128 {
+        crate::total_supply(e, id)
+    }
+
+    /// Transfers `amount` tokens of type `id` from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `id` - The token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   transfer more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[id: TokenId, amount: i128]`
+    fn transfer(e: &Env, from: Address, to: Address, id: TokenId, amount: i128) {
+        crate::transfer(e, &from, &to, id, amount);
+    }
+
+    /// Transfers `amount` tokens of type `id` from `from` to `to`, by an
+    /// operator approved by `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer, must be `from` or
+    ///   an operator approved by `from`.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `id` - The token type.
+    /// * `amount` - The amount of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientApproval`] - When `spender` is not
+    ///   allowed to manage the tokens of `from`.
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   transfer more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When `amount < 0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[id: TokenId, amount: i128]`
+    fn transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        id: TokenId,
+        amount: i128,
+    ) {
+        crate::transfer_from(e, &spender, &from, &to, id, amount);
+    }
+
+    /// Transfers `amounts[i]` tokens of type `ids[i]` from `from` to `to`, for
+    /// every index `i`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `ids` - The token types.
+    /// * `amounts` - The amounts of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::LengthMismatch`] - When `ids` and `amounts` have
+    ///   different lengths.
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   transfer more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When an amount is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_transfer", from: Address, to: Address]`
+    /// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+    fn batch_transfer(e: &Env, from: Address, to: Address, ids: Vec<TokenId>, amounts: Vec<i128>) {
+        crate::batch_transfer(e, &from, &to, &ids, &amounts);
+    }
+
+    /// Transfers `amounts[i]` tokens of type `ids[i]` from `from` to `to`, for
+    /// every index `i`, by an operator approved by `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer, must be `from` or
+    ///   an operator approved by `from`.
+    /// * `from` - The address holding the tokens.
+    /// * `to` - The address receiving the transferred tokens.
+    /// * `ids` - The token types.
+    /// * `amounts` - The amounts of tokens to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InsufficientApproval`] - When `spender` is not
+    ///   allowed to manage the tokens of `from`.
+    /// * [`MultiTokenError::LengthMismatch`] - When `ids` and `amounts` have
+    ///   different lengths.
+    /// * [`MultiTokenError::InsufficientBalance`] - When attempting to
+    ///   transfer more tokens than `from` current balance.
+    /// * [`MultiTokenError::LessThanZero`] - When an amount is negative.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_transfer", from: Address, to: Address]`
+    /// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+    fn batch_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        ids: Vec<TokenId>,
+        amounts: Vec<i128>,
+    ) {
+        crate::batch_transfer_from(e, &spender, &from, &to, &ids, &amounts);
+    }
+
+    /// Approves or removes `operator` as an operator for the owner, allowing
+    /// it to transfer every token of `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - Account to add to the set of authorized operators.
+    /// * `live_until_ledger` - The ledger number at which the approval
+    ///   expires. If `live_until_ledger` is `0`, the approval is revoked.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::InvalidLiveUntilLedger`] - If the ledger number is
+    ///   less than the current ledger number, and not `0`.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve_for_all", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    fn approve_for_all(e: &Env, owner: Address, operator: Address, live_until_ledger: u32) {
+        crate::approve_for_all(e, &owner, &operator, live_until_ledger);
+    }
+
+    /// Returns whether `operator` is allowed to manage all the tokens of
+    /// `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The address to check.
+    fn is_approved_for_all(e: &Env, owner: Address, operator: Address) -> bool {
+        crate::is_approved_for_all(e, &owner, &operator)
+    }
+
+    /// Returns the URI of the token type `id`, composed from the base URI
+    /// and `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `id` - The token type.
+    ///
+    /// # Errors
+    ///
+    /// * [`MultiTokenError::UnsetMetadata`] - When the base URI is not set.
+    fn uri(e: &Env, id: TokenId) -> String {
+        crate::uri(e, id)
+    }
+}
+
+// ################## ERRORS ##################
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MultiTokenError {
+    /// Indicates an error related to the current balance of account from which
+    /// tokens are expected to be transferred.
+    InsufficientBalance = 400,
+    /// Indicates a failure with the operator of a transfer.
+    InsufficientApproval = 401,
+    /// Indicates an invalid value for `live_until_ledger` when setting an
+    /// approval.
+    InvalidLiveUntilLedger = 402,
+    /// Indicates an error when an input that must be >= 0
+    LessThanZero = 403,
+    /// Indicates overflow when adding two values
+    MathOverflow = 404,
+    /// Indicates the lengths of the arrays of a batch operation differ.
+    LengthMismatch = 405,
+    /// Indicates access to unset metadata
+    UnsetMetadata = 406,
+    /// Indicates the length of the base URI exceeds the maximum allowed
+    BaseUriMaxLenExceeded = 407,
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a transfer of tokens of a single type.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+pub fn emit_transfer(e: &Env, from: &Address, to: &Address, id: TokenId, amount: i128) {
+    let topics = (symbol_short!("transfer"), from, to);
+    e.events().publish(topics, (id, amount))
+}
+
+/// Emits an event indicating a transfer of tokens of multiple types.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to be transferred.
+///
+/// # Events
+///
+/// * topics - `["batch_transfer", from: Address, to: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+pub fn emit_batch_transfer(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    ids: &Vec<TokenId>,
+    amounts: &Vec<i128>,
+) {
+    let topics = (Symbol::new(e, "batch_transfer"), from, to);
+    e.events().publish(topics, (ids.clone(), amounts.clone()))
+}
+
+/// Emits an event when `owner` enables or disables (`live_until_ledger` is
+/// `0`) `operator` to manage all of its tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `operator` - Address of an operator that will manage the tokens.
+/// * `live_until_ledger` - The ledger number at which the approval expires. If
+///   `live_until_ledger` is `0`, the approval is revoked.
+///
+/// # Events
+///
+/// * topics - `["approve_for_all", owner: Address]`
+/// * data - `[operator: Address, live_until_ledger: u32]`
+pub fn emit_approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+    let topics = (Symbol::new(e, "approve_for_all"), owner);
+    e.events().publish(topics, (operator, live_until_ledger))
+}
@@ -0,0 +1,483 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, String, Vec};
+use stellar_constants::{
+    BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
+};
+use stellar_non_fungible::{ApprovalForAllData, Base, MAX_BASE_URI_LEN};
+
+use crate::{
+    multi_token::{emit_approve_for_all, emit_batch_transfer, emit_transfer, MultiTokenError},
+    TokenId,
+};
+
+/// Storage key that maps to the balance of an account for a token type
+#[contracttype]
+pub struct BalanceKey {
+    pub account: Address,
+    pub id: TokenId,
+}
+
+/// Storage keys for the data associated with `MultiToken`
+#[contracttype]
+pub enum StorageKey {
+    Balance(BalanceKey),
+    TotalSupply(TokenId),
+    ApprovalForAll(Address),
+    BaseUri,
+}
+
+// ################## QUERY STATE ##################
+
+/// Returns the amount of tokens of type `id` held by `account`. Defaults to
+/// `0` if no balance is stored.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The address for which the balance is being queried.
+/// * `id` - The token type.
+pub fn balance_of(e: &Env, account: &Address, id: TokenId) -> i128 {
+    let key = StorageKey::Balance(BalanceKey { account: account.clone(), id });
+    if let Some(balance) = e.storage().persistent().get::<_, i128>(&key) {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        balance
+    } else {
+        0
+    }
+}
+
+/// Returns the balances of multiple `(account, id)` pairs.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `accounts` - The addresses for which the balances are being queried.
+/// * `ids` - The token types.
+///
+/// # Errors
+///
+/// * [`MultiTokenError::LengthMismatch`] - When `accounts` and `ids` have
+///   different lengths.
+pub fn balance_of_batch(e: &Env, accounts: &Vec<Address>, ids: &Vec<TokenId>) -> Vec<i128> {
+    if accounts.len() != ids.len() {
+        panic_with_error!(e, MultiTokenError::LengthMismatch);
+    }
+
+    let mut balances = Vec::new(e);
+    for (account, id) in accounts.iter().zip(ids.iter()) {
+        balances.push_back(balance_of(e, &account, id));
+    }
+    balances
+}
+
+/// Returns the amount of tokens of type `id` in circulation. Defaults to `0`
+/// if no supply is recorded.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `id` - The token type.
+pub fn total_supply(e: &Env, id: TokenId) -> i128 {
+    let key = StorageKey::TotalSupply(id);
+    if let Some(supply) = e.storage().persistent().get::<_, i128>(&key) {
+        e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        supply
+    } else {
+        0
+    }
+}
+
+/// Returns whether `operator` is allowed to manage all the tokens of `owner`:
+/// * `true` - If the operator has a valid, non-expired approval
+/// * `false` - If there is no approval or if the approval has expired
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `operator` - The address to check.
+pub fn is_approved_for_all(e: &Env, owner: &Address, operator: &Address) -> bool {
+    let key = StorageKey::ApprovalForAll(owner.clone());
+
+    if let Some(approval_data) = e.storage().temporary().get::<_, ApprovalForAllData>(&key) {
+        if let Some(expiry) = approval_data.operators.get(operator.clone()) {
+            return expiry >= e.ledger().sequence();
+        }
+    }
+
+    false
+}
+
+/// Returns the base URI of the token types.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`MultiTokenError::UnsetMetadata`] - When the base URI is not set.
+pub fn base_uri(e: &Env) -> String {
+    e.storage()
+        .instance()
+        .get(&StorageKey::BaseUri)
+        .unwrap_or_else(|| panic_with_error!(e, MultiTokenError::UnsetMetadata))
+}
+
+/// Returns the URI of the token type `id`, i.e. `{base_uri}/{id}`. An empty
+/// base URI results in an empty URI.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `id` - The token type.
+///
+/// # Errors
+///
+/// * refer to [`base_uri`] errors.
+pub fn uri(e: &Env, id: TokenId) -> String {
+    Base::compose_uri_for_token(e, base_uri(e), id)
+}
+
+// ################## CHANGE STATE ##################
+
+/// Sets the base URI of the token types.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `base_uri` - The base URI, the token id is appended to it to compose
+///   the URI of a token type.
+///
+/// # Errors
+///
+/// * [`MultiTokenError::BaseUriMaxLenExceeded`] - If the length of
+///   `base_uri` exceeds the maximum allowed.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls. You want to
+/// invoke it most likely from the constructor, or from an admin-only
+/// function.
+pub fn set_base_uri(e: &Env, base_uri: &String) {
+    if base_uri.len() as usize > MAX_BASE_URI_LEN {
+        panic_with_error!(e, MultiTokenError::BaseUriMaxLenExceeded)
+    }
+    e.storage().instance().set(&StorageKey::BaseUri, base_uri);
+}
+
+/// Transfers `amount` tokens of type `id` from `from` to `to`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Errors
+///
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn transfer(e: &Env, from: &Address, to: &Address, id: TokenId, amount: i128) {
+    from.require_auth();
+    update(e, Some(from), Some(to), id, amount);
+    emit_transfer(e, from, to, id, amount);
+}
+
+/// Transfers `amount` tokens of type `id` from `from` to `to`, by `from` or
+/// an operator approved by `from`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `spender` - The address authorizing the transfer.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Errors
+///
+/// * refer to [`check_operator`] errors.
+/// * refer to [`update`] errors.
+///
+/// # Events
+///
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[id: TokenId, amount: i128]`
+///
+/// # Notes
+///
+/// Authorization for `spender` is required.
+pub fn transfer_from(
+    e: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    id: TokenId,
+    amount: i128,
+) {
+    spender.require_auth();
+    check_operator(e, spender, from);
+    update(e, Some(from), Some(to), id, amount);
+    emit_transfer(e, from, to, id, amount);
+}
+
+/// Transfers `amounts[i]` tokens of type `ids[i]` from `from` to `to`, for
+/// every index `i`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to be transferred.
+///
+/// # Errors
+///
+/// * refer to [`batch_update`] errors.
+///
+/// # Events
+///
+/// * topics - `["batch_transfer", from: Address, to: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+///
+/// # Notes
+///
+/// Authorization for `from` is required.
+pub fn batch_transfer(
+    e: &Env,
+    from: &Address,
+    to: &Address,
+    ids: &Vec<TokenId>,
+    amounts: &Vec<i128>,
+) {
+    from.require_auth();
+    batch_update(e, Some(from), Some(to), ids, amounts);
+    emit_batch_transfer(e, from, to, ids, amounts);
+}
+
+/// Transfers `amounts[i]` tokens of type `ids[i]` from `from` to `to`, for
+/// every index `i`, by `from` or an operator approved by `from`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `spender` - The address authorizing the transfer.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to be transferred.
+///
+/// # Errors
+///
+/// * refer to [`check_operator`] errors.
+/// * refer to [`batch_update`] errors.
+///
+/// # Events
+///
+/// * topics - `["batch_transfer", from: Address, to: Address]`
+/// * data - `[ids: Vec<TokenId>, amounts: Vec<i128>]`
+///
+/// # Notes
+///
+/// Authorization for `spender` is required.
+pub fn batch_transfer_from(
+    e: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    ids: &Vec<TokenId>,
+    amounts: &Vec<i128>,
+) {
+    spender.require_auth();
+    check_operator(e, spender, from);
+    batch_update(e, Some(from), Some(to), ids, amounts);
+    emit_batch_transfer(e, from, to, ids, amounts);
+}
+
+/// Approves or removes `operator` as an operator for `owner`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `owner` - The address holding the tokens.
+/// * `operator` - Account to add to the set of authorized operators.
+/// * `live_until_ledger` - The ledger number at which the approval expires.
+///   If `live_until_ledger` is `0`, the approval is revoked.
+///
+/// # Errors
+///
+/// * [`MultiTokenError::InvalidLiveUntilLedger`] - If the ledger number is
+///   less than the current ledger number, and not `0`.
+///
+/// # Events
+///
+/// * topics - `["approve_for_all", owner: Address]`
+/// * data - `[operator: Address, live_until_ledger: u32]`
+///
+/// # Notes
+///
+/// Authorization for `owner` is required.
+pub fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+    owner.require_auth();
+
+    let key = StorageKey::ApprovalForAll(owner.clone());
+
+    // If revoking approval (live_until_ledger == 0)
+    if live_until_ledger == 0 {
+        if let Some(mut approval_data) = e.storage().temporary().get::<_, ApprovalForAllData>(&key)
+        {
+            approval_data.operators.remove(operator.clone());
+            // NOTE: the TTL is not touched, the remaining operators keep their
+            // expiration ledgers.
+            e.storage().temporary().set(&key, &approval_data);
+        }
+        emit_approve_for_all(e, owner, operator, live_until_ledger);
+        return;
+    }
+
+    if live_until_ledger < e.ledger().sequence() {
+        panic_with_error!(e, MultiTokenError::InvalidLiveUntilLedger);
+    }
+
+    let mut approval_data = e
+        .storage()
+        .temporary()
+        .get::<_, ApprovalForAllData>(&key)
+        .unwrap_or_else(|| ApprovalForAllData { operators: Map::new(e) });
+
+    approval_data.operators.set(operator.clone(), live_until_ledger);
+
+    e.storage().temporary().set(&key, &approval_data);
+
+    let live_for = live_until_ledger - e.ledger().sequence();
+    e.storage().temporary().extend_ttl(&key, live_for, live_for);
+
+    emit_approve_for_all(e, owner, operator, live_until_ledger);
+}
+
+// ################## LOW-LEVEL HELPERS ##################
+
+/// Low-level function for checking that `spender` is either `from` or an
+/// operator approved by `from`, without handling authorization.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `spender` - The address attempting to transfer the tokens.
+/// * `from` - The address holding the tokens.
+///
+/// # Errors
+///
+/// * [`MultiTokenError::InsufficientApproval`] - If `spender` is neither
+///   `from` nor an approved operator of `from`.
+pub fn check_operator(e: &Env, spender: &Address, from: &Address) {
+    if spender != from && !is_approved_for_all(e, from, spender) {
+        panic_with_error!(e, MultiTokenError::InsufficientApproval);
+    }
+}
+
+/// Transfers `amount` tokens of type `id` from `from` to `to` or
+/// alternatively mints (or burns) tokens if `from` (or `to`) is `None`.
+/// Updates the total supply of `id` accordingly.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `id` - The token type.
+/// * `amount` - The amount of tokens to be transferred.
+///
+/// # Errors
+///
+/// * [`MultiTokenError::LessThanZero`] - When `amount < 0`.
+/// * [`MultiTokenError::InsufficientBalance`] - When attempting to transfer
+///   more tokens than `from` current balance.
+/// * [`MultiTokenError::MathOverflow`] - When the total supply of `id`
+///   overflows.
+///
+/// # Notes
+///
+/// No authorization is required.
+pub fn update(e: &Env, from: Option<&Address>, to: Option<&Address>, id: TokenId, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, MultiTokenError::LessThanZero);
+    }
+    if let Some(account) = from {
+        let mut from_balance = balance_of(e, account, id);
+        if from_balance < amount {
+            panic_with_error!(e, MultiTokenError::InsufficientBalance);
+        }
+        // NOTE: can't underflow because of the check above.
+        from_balance -= amount;
+        let key = StorageKey::Balance(BalanceKey { account: account.clone(), id });
+        e.storage().persistent().set(&key, &from_balance);
+    } else {
+        // `from` is None, so we're minting tokens.
+        let Some(new_total_supply) = total_supply(e, id).checked_add(amount) else {
+            panic_with_error!(e, MultiTokenError::MathOverflow);
+        };
+        e.storage().persistent().set(&StorageKey::TotalSupply(id), &new_total_supply);
+    }
+
+    if let Some(account) = to {
+        // NOTE: can't overflow because balance + amount is at most total_supply.
+        let to_balance = balance_of(e, account, id) + amount;
+        let key = StorageKey::Balance(BalanceKey { account: account.clone(), id });
+        e.storage().persistent().set(&key, &to_balance);
+    } else {
+        // `to` is None, so we're burning tokens.
+
+        // NOTE: can't underflow because amount <= from_balance <= total_supply.
+        let new_total_supply = total_supply(e, id) - amount;
+        e.storage().persistent().set(&StorageKey::TotalSupply(id), &new_total_supply);
+    }
+}
+
+/// Same as [`update`], for multiple token types: `amounts[i]` tokens of type
+/// `ids[i]` are moved, for every index `i`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `ids` - The token types.
+/// * `amounts` - The amounts of tokens to be transferred.
+///
+/// # Errors
+///
+/// * [`MultiTokenError::LengthMismatch`] - When `ids` and `amounts` have
+///   different lengths.
+/// * refer to [`update`] errors.
+///
+/// # Notes
+///
+/// No authorization is required.
+pub fn batch_update(
+    e: &Env,
+    from: Option<&Address>,
+    to: Option<&Address>,
+    ids: &Vec<TokenId>,
+    amounts: &Vec<i128>,
+) {
+    if ids.len() != amounts.len() {
+        panic_with_error!(e, MultiTokenError::LengthMismatch);
+    }
+
+    for (id, amount) in ids.iter().zip(amounts.iter()) {
+        update(e, from, to, id, amount);
+    }
+}
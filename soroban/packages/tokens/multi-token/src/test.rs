@@ -0,0 +1,375 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env, String,
+};
+use stellar_event_assertion::EventAssertion;
+use stellar_non_fungible::MAX_BASE_URI_LEN;
+
+use crate::{
+    approve_for_all, balance_of, balance_of_batch, batch_transfer, batch_transfer_from,
+    is_approved_for_all, mintable::mint, set_base_uri, total_supply, transfer, transfer_from, uri,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn initial_state() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(balance_of(&e, &account, 1), 0);
+        assert_eq!(total_supply(&e, 1), 0);
+    });
+}
+
+#[test]
+fn transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 1, 100);
+        transfer(&e, &from, &recipient, 1, 40);
+
+        assert_eq!(balance_of(&e, &from, 1), 60);
+        assert_eq!(balance_of(&e, &recipient, 1), 40);
+        assert_eq!(total_supply(&e, 1), 100);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_multi_token_mint(&from, 1, 100);
+        event_assert.assert_multi_token_transfer(&from, &recipient, 1, 40);
+    });
+}
+
+#[test]
+fn balances_are_tracked_per_id() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account, 1, 100);
+        mint(&e, &account, 2, 1);
+
+        assert_eq!(balance_of(&e, &account, 1), 100);
+        assert_eq!(balance_of(&e, &account, 2), 1);
+        assert_eq!(balance_of(&e, &account, 3), 0);
+        assert_eq!(total_supply(&e, 1), 100);
+        assert_eq!(total_supply(&e, 2), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #400)")]
+fn transfer_insufficient_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 1, 100);
+        transfer(&e, &from, &recipient, 1, 101);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #403)")]
+fn transfer_negative_amount_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 1, 100);
+        transfer(&e, &from, &recipient, 1, -1);
+    });
+}
+
+#[test]
+fn balance_of_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let account1 = Address::generate(&e);
+    let account2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &account1, 1, 10);
+        mint(&e, &account2, 2, 20);
+
+        let balances = balance_of_batch(
+            &e,
+            &vec![&e, account1.clone(), account2.clone(), account1.clone()],
+            &vec![&e, 1, 2, 2],
+        );
+        assert_eq!(balances, vec![&e, 10, 20, 0]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #405)")]
+fn balance_of_batch_length_mismatch_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        balance_of_batch(&e, &vec![&e, account], &vec![&e, 1, 2]);
+    });
+}
+
+#[test]
+fn batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 1, 100);
+        mint(&e, &from, 2, 5);
+
+        let ids = vec![&e, 1, 2];
+        let amounts = vec![&e, 30, 5];
+        batch_transfer(&e, &from, &recipient, &ids, &amounts);
+
+        assert_eq!(balance_of(&e, &from, 1), 70);
+        assert_eq!(balance_of(&e, &from, 2), 0);
+        assert_eq!(balance_of(&e, &recipient, 1), 30);
+        assert_eq!(balance_of(&e, &recipient, 2), 5);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_multi_token_batch_transfer(&from, &recipient, &ids, &amounts);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #405)")]
+fn batch_transfer_length_mismatch_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let from = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &from, 1, 100);
+        batch_transfer(&e, &from, &recipient, &vec![&e, 1], &vec![&e, 10, 10]);
+    });
+}
+
+#[test]
+fn approve_for_all_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve_for_all(&e, &owner, &operator, 1000);
+
+        assert!(is_approved_for_all(&e, &owner, &operator));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_approve_for_all(&owner, &operator, 1000);
+    });
+}
+
+#[test]
+fn approve_for_all_expires() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve_for_all(&e, &owner, &operator, 5);
+
+        e.ledger().set_sequence_number(5);
+        assert!(is_approved_for_all(&e, &owner, &operator));
+
+        e.ledger().set_sequence_number(6);
+        assert!(!is_approved_for_all(&e, &owner, &operator));
+    });
+}
+
+#[test]
+fn revoke_approve_for_all_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        approve_for_all(&e, &owner, &operator, 0);
+
+        assert!(!is_approved_for_all(&e, &owner, &operator));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_approve_for_all(&owner, &operator, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #402)")]
+fn approve_for_all_with_past_ledger_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(10);
+        approve_for_all(&e, &owner, &operator, 5);
+    });
+}
+
+#[test]
+fn transfer_from_by_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1, 100);
+        approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        transfer_from(&e, &operator, &owner, &recipient, 1, 25);
+
+        assert_eq!(balance_of(&e, &owner, 1), 75);
+        assert_eq!(balance_of(&e, &recipient, 1), 25);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_multi_token_transfer(&owner, &recipient, 1, 25);
+    });
+}
+
+#[test]
+fn batch_transfer_from_by_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1, 100);
+        mint(&e, &owner, 2, 100);
+        approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        batch_transfer_from(&e, &operator, &owner, &recipient, &vec![&e, 1, 2], &vec![&e, 10, 20]);
+
+        assert_eq!(balance_of(&e, &recipient, 1), 10);
+        assert_eq!(balance_of(&e, &recipient, 2), 20);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn transfer_from_without_approval_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1, 100);
+        transfer_from(&e, &spender, &owner, &recipient, 1, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")]
+fn transfer_from_with_expired_approval_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        mint(&e, &owner, 1, 100);
+        approve_for_all(&e, &owner, &operator, 5);
+    });
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(6);
+        transfer_from(&e, &operator, &owner, &recipient, 1, 10);
+    });
+}
+
+#[test]
+fn uri_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_base_uri(&e, &String::from_str(&e, "https://example.com/"));
+
+        assert_eq!(uri(&e, 42), String::from_str(&e, "https://example.com/42"));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #406)")]
+fn uri_without_base_uri_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        uri(&e, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #407)")]
+fn set_base_uri_too_long_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        let long_uri = "a".repeat(MAX_BASE_URI_LEN + 1);
+        set_base_uri(&e, &String::from_str(&e, &long_uri));
+    });
+}
@@ -0,0 +1,25 @@
+/// Like the `capped` extension, the `allowlist` extension does not provide a
+/// separate trait, as its methods are meant to back a custom minting function
+/// of the contract rather than being exposed as they are.
+///
+/// The allowlisted accounts are committed to with the root of a Merkle tree
+/// (see `stellar_merkle`), so that thousands of accounts can be allowlisted
+/// without storing each of them. Every leaf is the `sha256` hash of the
+/// account and the number of tokens it is allowed to mint, as returned by
+/// `leaf`. The number of tokens minted by each account is tracked, so that
+/// an account can mint its allowance over multiple calls.
+///
+/// This module provides the following helper functions:
+/// - `set_allowlist_root`: Sets the root of the allowlist.
+/// - `allowlist_root`: Returns the root of the allowlist.
+/// - `leaf`: Returns the leaf of an `(account, allowance)` pair.
+/// - `claimed`: Returns the number of tokens minted by an account through
+///   the allowlist.
+/// - `allowlist_mint`: Verifies an `(account, allowance)` leaf and mints a
+///   token with [`crate::Base::sequential_mint`].
+mod storage;
+pub use self::storage::{
+    allowlist_mint, allowlist_root, claimed, leaf, set_allowlist_root, StorageKey,
+    ALLOWLIST_ROOT_KEY,
+};
+mod test;
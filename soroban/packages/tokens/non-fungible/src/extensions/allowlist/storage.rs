@@ -0,0 +1,136 @@
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, xdr::ToXdr, Address, BytesN, Env, Symbol, Vec,
+};
+use stellar_constants::{BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD};
+
+use crate::{Balance, Base, NonFungibleTokenError, TokenId};
+
+/// Storage key for the root of the allowlist.
+pub const ALLOWLIST_ROOT_KEY: Symbol = symbol_short!("AL_ROOT");
+
+/// Storage keys for the data associated with the `allowlist` extension
+#[contracttype]
+pub enum StorageKey {
+    Claimed(Address),
+}
+
+/// Returns the root of the allowlist.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::AllowlistNotSet`] - Occurs when the root has
+///   not been set.
+pub fn allowlist_root(e: &Env) -> BytesN<32> {
+    e.storage()
+        .instance()
+        .get(&ALLOWLIST_ROOT_KEY)
+        .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::AllowlistNotSet))
+}
+
+/// Returns the number of tokens `account` minted through the allowlist.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The allowlisted account.
+pub fn claimed(e: &Env, account: &Address) -> Balance {
+    let key = StorageKey::Claimed(account.clone());
+    if let Some(claimed) = e.storage().persistent().get::<_, Balance>(&key) {
+        e.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_EXTEND_AMOUNT);
+        claimed
+    } else {
+        0
+    }
+}
+
+/// Returns the leaf of the allowlist tree for `account` and `allowance`, i.e.
+/// `sha256(sha256(account.to_xdr() || allowance.to_be_bytes()))`, see
+/// [`stellar_merkle::hash_leaf`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `account` - The allowlisted account.
+/// * `allowance` - The number of tokens `account` is allowed to mint.
+pub fn leaf(e: &Env, account: &Address, allowance: Balance) -> BytesN<32> {
+    let mut data = account.clone().to_xdr(e);
+    data.extend_from_array(&allowance.to_be_bytes());
+    stellar_merkle::hash_leaf(e, &data)
+}
+
+/// Sets the root of the allowlist.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `root` - The root of the allowlist tree.
+///
+/// # Notes
+///
+/// * We recommend using this function in the constructor of your smart
+///   contract.
+/// * The number of tokens already minted by each account is kept when the
+///   root changes, and is counted against the allowances of the new tree.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: This function lacks authorization controls. If it is not
+/// invoked from the constructor, it should be invoked from an admin-only
+/// function.
+pub fn set_allowlist_root(e: &Env, root: &BytesN<32>) {
+    e.storage().instance().set(&ALLOWLIST_ROOT_KEY, root);
+}
+
+/// Mints a token to `to` with [`Base::sequential_mint`], after verifying that
+/// `to` is allowlisted with `allowance` and has not minted its allowance yet.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `to` - The allowlisted account receiving the token.
+/// * `allowance` - The number of tokens `to` is allowed to mint, as encoded
+///   in its leaf.
+/// * `proof` - The Merkle proof of the leaf of `to`.
+///
+/// # Errors
+///
+/// * refer to [`allowlist_root`] errors.
+/// * [`NonFungibleTokenError::InvalidAllowlistProof`] - Occurs when the leaf
+///   of `to` and `allowance` is not part of the allowlist.
+/// * [`NonFungibleTokenError::AllowlistAllowanceExceeded`] - Occurs when `to`
+///   already minted `allowance` tokens.
+/// * refer to [`Base::sequential_mint`] errors.
+///
+/// # Events
+///
+/// * topics - `["mint", to: Address]`
+/// * data - `[token_id: TokenId]`
+///
+/// # Security Warning
+///
+/// This function lacks authorization controls, similarly to
+/// [`Base::sequential_mint`]. The token always goes to the allowlisted
+/// account, but any caller can use up its allowance. Require the
+/// authorization of `to` in your contract, if this matters for your use case.
+pub fn allowlist_mint(
+    e: &Env,
+    to: &Address,
+    allowance: Balance,
+    proof: &Vec<BytesN<32>>,
+) -> TokenId {
+    if !stellar_merkle::verify(e, proof, &allowlist_root(e), &leaf(e, to, allowance)) {
+        panic_with_error!(e, NonFungibleTokenError::InvalidAllowlistProof);
+    }
+
+    let minted = claimed(e, to);
+    if minted >= allowance {
+        panic_with_error!(e, NonFungibleTokenError::AllowlistAllowanceExceeded);
+    }
+    e.storage().persistent().set(&StorageKey::Claimed(to.clone()), &(minted + 1));
+
+    Base::sequential_mint(e, to)
+}
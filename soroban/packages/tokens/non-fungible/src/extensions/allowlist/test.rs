@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, BytesN, Env, Vec};
+use stellar_event_assertion::EventAssertion;
+use stellar_merkle::hash_pair;
+
+use crate::{
+    allowlist::{allowlist_mint, allowlist_root, claimed, leaf, set_allowlist_root},
+    capped::set_cap,
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+/// Builds an allowlist of 2 accounts, and returns its root.
+fn allowlist(e: &Env, alice: &Address, bob: &Address) -> BytesN<32> {
+    hash_pair(e, &leaf(e, alice, 2), &leaf(e, bob, 1))
+}
+
+#[test]
+fn allowlist_mint_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let root = allowlist(&e, &alice, &bob);
+        set_allowlist_root(&e, &root);
+        assert_eq!(allowlist_root(&e), root);
+
+        let token_id = allowlist_mint(&e, &alice, 2, &vec![&e, leaf(&e, &bob, 1)]);
+
+        assert_eq!(Base::owner_of(&e, token_id), alice);
+        assert_eq!(claimed(&e, &alice), 1);
+        assert_eq!(claimed(&e, &bob), 0);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_mint(&alice, token_id);
+    });
+}
+
+#[test]
+fn allowlist_mint_up_to_allowance_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowlist_root(&e, &allowlist(&e, &alice, &bob));
+
+        let proof = vec![&e, leaf(&e, &bob, 1)];
+        allowlist_mint(&e, &alice, 2, &proof);
+        allowlist_mint(&e, &alice, 2, &proof);
+
+        assert_eq!(Base::balance(&e, &alice), 2);
+        assert_eq!(claimed(&e, &alice), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #323)")]
+fn allowlist_mint_over_allowance_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowlist_root(&e, &allowlist(&e, &alice, &bob));
+
+        let proof = vec![&e, leaf(&e, &alice, 2)];
+        allowlist_mint(&e, &bob, 1, &proof);
+        allowlist_mint(&e, &bob, 1, &proof);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #322)")]
+fn allowlist_mint_with_inflated_allowance_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowlist_root(&e, &allowlist(&e, &alice, &bob));
+
+        allowlist_mint(&e, &bob, 5, &vec![&e, leaf(&e, &alice, 2)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #322)")]
+fn allowlist_mint_not_allowlisted_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let eve = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_allowlist_root(&e, &allowlist(&e, &alice, &bob));
+
+        allowlist_mint(&e, &eve, 1, &Vec::new(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #321)")]
+fn allowlist_mint_without_root_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        allowlist_mint(&e, &alice, 1, &Vec::new(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn allowlist_mint_respects_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 1);
+        set_allowlist_root(&e, &allowlist(&e, &alice, &bob));
+
+        let proof = vec![&e, leaf(&e, &bob, 1)];
+        allowlist_mint(&e, &alice, 2, &proof);
+        allowlist_mint(&e, &alice, 2, &proof);
+    });
+}
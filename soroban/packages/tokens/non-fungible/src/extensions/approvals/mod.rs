@@ -0,0 +1,91 @@
+//! # Approvals Extension for Non-Fungible Token
+//!
+//! The `approvals` module exposes the approvals of a collection, so that
+//! wallets and marketplaces can list who is able to move the tokens of an
+//! account, and until when, without replaying the `approve` events.
+//!
+//! Only the approvals that are not expired are returned.
+//!
+//! The approval of a token can also be revoked with `revoke_approval`, which
+//! resolves the owner of the token through the contract type, so that it
+//! works within a batch of a consecutive collection as well.
+pub mod storage;
+
+mod test;
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{ApprovalData, NonFungibleToken, TokenId};
+
+/// Approvals Trait for Non-Fungible Token
+///
+/// The `NonFungibleApprovals` trait extends the `NonFungibleToken` trait to
+/// provide queries over the approvals of the tokens and the operators of the
+/// accounts, and to revoke the approval of a token. This trait is designed to be used in conjunction with the
+/// `NonFungibleToken` trait, and is compatible with every contract type.
+///
+/// # Notes
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`NonFungibleApprovals`] trait for your Smart Contract,
+/// you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl NonFungibleApprovals for MyContract {
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait NonFungibleApprovals: NonFungibleToken {
+    /// Returns the operators of `owner` alongside the ledger number at which
+    /// their approval expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    fn operators_of(e: &Env, owner: Address) -> Vec<(Address, u32)>;
+
+    /// Returns the approval of `token_id`, if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn approval_of(e: &Env, token_id: TokenId) -> Option<ApprovalData>;
+
+    /// Revokes the approval of `token_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address revoking the approval (should be `owner` or
+    ///   `operator`).
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::NonExistentToken`] - If the token
+    ///   does not exist.
+    /// * [`crate::NonFungibleTokenError::InvalidApprover`] - If `approver` is
+    ///   neither the owner of the token nor one of its operators.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// The event is emitted with `live_until_ledger` set to `0`, and only if
+    /// the token had an approval.
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `approver` is required.
+    fn revoke_approval(e: &Env, approver: Address, token_id: TokenId);
+}
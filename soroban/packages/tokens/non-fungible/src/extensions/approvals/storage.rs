@@ -0,0 +1,57 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    consecutive::{storage::StorageKey as ConsecutiveStorageKey, Consecutive},
+    ApprovalData, ApprovalForAllData, Base, StorageKey, TokenId,
+};
+
+impl Base {
+    /// Returns the operators of `owner` that are not expired, alongside the
+    /// ledger number at which their approval expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    pub fn operators_of(e: &Env, owner: &Address) -> Vec<(Address, u32)> {
+        let mut operators = Vec::new(e);
+        let key = StorageKey::ApprovalForAll(owner.clone());
+        if let Some(approval_data) = e.storage().temporary().get::<_, ApprovalForAllData>(&key) {
+            for (operator, live_until_ledger) in approval_data.operators.iter() {
+                if live_until_ledger >= e.ledger().sequence() {
+                    operators.push_back((operator, live_until_ledger));
+                }
+            }
+        }
+        operators
+    }
+
+    /// Returns the approval of `token_id`, unless there is none or it is
+    /// expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn approval_of(e: &Env, token_id: TokenId) -> Option<ApprovalData> {
+        live_approval(e, e.storage().temporary().get(&StorageKey::Approval(token_id)))
+    }
+}
+
+impl Consecutive {
+    /// Returns the approval of `token_id`, unless there is none or it is
+    /// expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn approval_of(e: &Env, token_id: TokenId) -> Option<ApprovalData> {
+        let key = ConsecutiveStorageKey::Approval(token_id);
+        live_approval(e, e.storage().temporary().get(&key))
+    }
+}
+
+fn live_approval(e: &Env, approval_data: Option<ApprovalData>) -> Option<ApprovalData> {
+    approval_data.filter(|approval_data| approval_data.live_until_ledger >= e.ledger().sequence())
+}
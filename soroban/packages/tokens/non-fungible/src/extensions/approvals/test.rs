@@ -0,0 +1,238 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env,
+};
+
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    consecutive::Consecutive, enumerable_consecutive::EnumerableConsecutive, ApprovalData, Base,
+    ContractOverrides, StorageKey,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn operators_of_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator1 = Address::generate(&e);
+    let operator2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Base::operators_of(&e, &owner), vec![&e]);
+
+        Base::approve_for_all(&e, &owner, &operator1, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator2, 2000);
+    });
+
+    e.as_contract(&address, || {
+        let operators = Base::operators_of(&e, &owner);
+        assert_eq!(operators.len(), 2);
+        assert!(operators.contains((operator1.clone(), 1000)));
+        assert!(operators.contains((operator2.clone(), 2000)));
+    });
+}
+
+#[test]
+fn operators_of_skips_expired_operators() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let expired = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &expired, 10);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(11);
+
+        assert_eq!(Base::operators_of(&e, &owner), vec![&e, (operator.clone(), 1000)]);
+        assert_eq!(
+            <Base as ContractOverrides>::operators_of(&e, &owner),
+            vec![&e, (operator.clone(), 1000)]
+        );
+    });
+}
+
+#[test]
+fn approval_of_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert_eq!(Base::approval_of(&e, token_id), None);
+
+        Base::approve(&e, &owner, &approved, token_id, 1000);
+
+        let expected = ApprovalData { approved: approved.clone(), live_until_ledger: 1000 };
+        assert_eq!(Base::approval_of(&e, token_id), Some(expected));
+
+        e.ledger().set_sequence_number(1001);
+        assert_eq!(Base::approval_of(&e, token_id), None);
+    });
+}
+
+#[test]
+fn approval_of_cleared_on_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &approved, token_id, 1000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer(&e, &owner, &recipient, token_id);
+
+        assert_eq!(Base::approval_of(&e, token_id), None);
+        assert!(!e.storage().temporary().has(&StorageKey::Approval(token_id)));
+    });
+}
+
+#[test]
+fn consecutive_approval_of_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let token_id = 3;
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 5);
+        Consecutive::approve(&e, &owner, &approved, token_id, 1000);
+
+        let expected = ApprovalData { approved: approved.clone(), live_until_ledger: 1000 };
+        assert_eq!(Consecutive::approval_of(&e, token_id), Some(expected.clone()));
+        assert_eq!(<Consecutive as ContractOverrides>::approval_of(&e, token_id), Some(expected));
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &owner, &recipient, token_id);
+        assert_eq!(Consecutive::approval_of(&e, token_id), None);
+    });
+}
+
+#[test]
+fn consecutive_revoke_approval_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    // the token is within its batch, i.e. has no `Owner` entry
+    let token_id = 3;
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 5);
+        Consecutive::approve(&e, &owner, &approved, token_id, 1000);
+    });
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::revoke_approval(&e, &owner, token_id);
+        assert_eq!(Consecutive::approval_of(&e, token_id), None);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_approve(&owner, &approved, token_id, 0);
+    });
+}
+
+#[test]
+fn consecutive_revoke_approval_with_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 2;
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 5);
+        Consecutive::approve(&e, &owner, &approved, token_id, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::revoke_approval(&e, &operator, token_id);
+        assert_eq!(Consecutive::approval_of(&e, token_id), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn consecutive_revoke_approval_invalid_approver_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 1;
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 5);
+        Consecutive::approve(&e, &owner, &approved, token_id, 1000);
+    });
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::revoke_approval(&e, &approved, token_id);
+    });
+}
+
+#[test]
+fn enumerable_consecutive_revoke_approval_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 4;
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 5);
+        <EnumerableConsecutive as ContractOverrides>::approve(
+            &e, &owner, &approved, token_id, 1000,
+        );
+    });
+
+    e.as_contract(&address, || {
+        <EnumerableConsecutive as ContractOverrides>::revoke_approval(&e, &owner, token_id);
+        assert_eq!(<EnumerableConsecutive as ContractOverrides>::approval_of(&e, token_id), None);
+    });
+}
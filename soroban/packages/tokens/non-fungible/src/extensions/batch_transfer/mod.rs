@@ -0,0 +1,191 @@
+//! # Batch Transfer Extension for Non-Fungible Token
+//!
+//! The `batch_transfer` module allows moving several tokens from one account
+//! to another in a single call, e.g. to move a collection to a new custody
+//! address, and approving an address for several tokens at once. The
+//! authorization is required only once per batch.
+//!
+//! Every contract type keeps its own bookkeeping: the batch transfer functions
+//! are dispatched through [`crate::ContractOverrides`], so `Enumerable`,
+//! `Consecutive` and `EnumerableConsecutive` contracts update their
+//! enumerations and ownership records for each token, while `Soulbound`
+//! contracts reject batch transfers and approvals like any other transfer or
+//! approval.
+//!
+//! ## Events
+//!
+//! By default, a `transfer` event is emitted for every token, exactly as with
+//! single transfers. A contract can instead opt into a single aggregated
+//! `batch_transfer` event per batch with [`storage::set_aggregate_events`].
+//!
+//! ## Notes
+//!
+//! - A batch holds at most [`MAX_BATCH_TRANSFER_SIZE`] tokens, keeping it
+//!   within the resource limits of a transaction.
+//! - Batch approvals always emit an `approve` event per token.
+//! - The whole batch is reverted if any of the tokens cannot be transferred.
+pub mod storage;
+
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::{NonFungibleToken, TokenId};
+
+/// Max. number of tokens transferred with a single batch transfer.
+pub const MAX_BATCH_TRANSFER_SIZE: u32 = 50;
+
+/// Batch Transfer Trait for Non-Fungible Token
+///
+/// The `NonFungibleBatchTransfer` trait extends the `NonFungibleToken` trait
+/// to transfer or approve several tokens in a single call. This trait is
+/// designed to be used in conjunction with the `NonFungibleToken` trait, and is
+/// compatible with every contract type.
+///
+/// # Notes
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`NonFungibleBatchTransfer`] trait for your Smart
+/// Contract, you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl NonFungibleBatchTransfer for MyContract {
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait NonFungibleBatchTransfer: NonFungibleToken {
+    /// Transfers the `token_ids` tokens from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_ids` - The tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::InvalidBatchSize`] - If `token_ids`
+    ///   is empty or has more than [`MAX_BATCH_TRANSFER_SIZE`] entries.
+    /// * [`crate::NonFungibleTokenError::IncorrectOwner`] - If the current
+    ///   owner (before calling this function) of any of the tokens is not
+    ///   `from`.
+    /// * [`crate::NonFungibleTokenError::NonExistentToken`] - If any of the
+    ///   tokens does not exist.
+    ///
+    /// # Events
+    ///
+    /// For each token, unless aggregated events are enabled:
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// If aggregated events are enabled:
+    /// * topics - `["batch_transfer", from: Address, to: Address]`
+    /// * data - `[token_ids: Vec<TokenId>]`
+    fn batch_transfer(e: &Env, from: Address, to: Address, token_ids: Vec<TokenId>);
+
+    /// Transfers the `token_ids` tokens from `from` to `to` by using
+    /// `spender`s approval.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_ids` - The tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::InvalidBatchSize`] - If `token_ids`
+    ///   is empty or has more than [`MAX_BATCH_TRANSFER_SIZE`] entries.
+    /// * [`crate::NonFungibleTokenError::IncorrectOwner`] - If the current
+    ///   owner (before calling this function) of any of the tokens is not
+    ///   `from`.
+    /// * [`crate::NonFungibleTokenError::InsufficientApproval`] - If the
+    ///   spender does not have a valid approval for any of the tokens.
+    /// * [`crate::NonFungibleTokenError::NonExistentToken`] - If any of the
+    ///   tokens does not exist.
+    ///
+    /// # Events
+    ///
+    /// For each token, unless aggregated events are enabled:
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// If aggregated events are enabled:
+    /// * topics - `["batch_transfer", from: Address, to: Address]`
+    /// * data - `[token_ids: Vec<TokenId>]`
+    fn batch_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_ids: Vec<TokenId>,
+    );
+
+    /// Gives permission to `approved` to transfer each of the `token_ids`
+    /// tokens to another account. The approvals are cleared when the tokens
+    /// are transferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address of the approver (should be `owner` or
+    ///   `operator`).
+    /// * `approved` - The address receiving the approval.
+    /// * `token_ids` - The tokens to approve.
+    /// * `live_until_ledger` - The ledger number at which the approvals
+    ///   expire.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::InvalidBatchSize`] - If `token_ids`
+    ///   is empty or has more than [`MAX_BATCH_TRANSFER_SIZE`] entries.
+    /// * [`crate::NonFungibleTokenError::NonExistentToken`] - If any of the
+    ///   tokens does not exist.
+    /// * [`crate::NonFungibleTokenError::InvalidApprover`] - If `approver` is
+    ///   neither the owner nor an operator of any of the tokens.
+    /// * [`crate::NonFungibleTokenError::InvalidLiveUntilLedger`] - If the
+    ///   ledger number is less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    fn batch_approve(
+        e: &Env,
+        approver: Address,
+        approved: Address,
+        token_ids: Vec<TokenId>,
+        live_until_ledger: u32,
+    );
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating a transfer of multiple tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `token_ids` - The transferred tokens.
+///
+/// # Events
+///
+/// * topics - `["batch_transfer", from: Address, to: Address]`
+/// * data - `[token_ids: Vec<TokenId>]`
+pub fn emit_batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+    let topics = (Symbol::new(e, "batch_transfer"), from, to);
+    e.events().publish(topics, token_ids.clone())
+}
@@ -0,0 +1,546 @@
+use soroban_sdk::{panic_with_error, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{
+    batch_transfer::{emit_batch_transfer, MAX_BATCH_TRANSFER_SIZE},
+    consecutive::Consecutive,
+    emit_transfer,
+    enumerable::Enumerable,
+    enumerable_consecutive::EnumerableConsecutive,
+    soulbound::Soulbound,
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Storage key for the aggregated batch transfer events setting.
+pub const AGGREGATE_EVENTS_KEY: Symbol = symbol_short!("BATCH_EVT");
+
+/// Sets whether batch transfers emit a single `batch_transfer` event instead
+/// of a `transfer` event per token.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `aggregate` - Whether to emit a single event per batch.
+///
+/// # Security Warning
+///
+/// **IMPORTANT**: The function intentionally lacks authorization controls.
+/// We recommend using it in the constructor of your smart contract.
+pub fn set_aggregate_events(e: &Env, aggregate: bool) {
+    e.storage().instance().set(&AGGREGATE_EVENTS_KEY, &aggregate);
+}
+
+/// Returns whether batch transfers emit a single `batch_transfer` event.
+/// Defaults to `false`.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn aggregate_events(e: &Env) -> bool {
+    e.storage().instance().get(&AGGREGATE_EVENTS_KEY).unwrap_or(false)
+}
+
+/// Panics if `token_ids` can't be transferred in a single batch.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `token_ids` - The tokens to transfer.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::InvalidBatchSize`] - If `token_ids` is empty or
+///   has more than [`MAX_BATCH_TRANSFER_SIZE`] entries.
+pub fn check_batch_size(e: &Env, token_ids: &Vec<TokenId>) {
+    if token_ids.is_empty() || token_ids.len() > MAX_BATCH_TRANSFER_SIZE {
+        panic_with_error!(e, NonFungibleTokenError::InvalidBatchSize);
+    }
+}
+
+/// Emits either a single `batch_transfer` event or a `transfer` event per
+/// token, depending on [`aggregate_events`].
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `from` - The address holding the tokens.
+/// * `to` - The address receiving the transferred tokens.
+/// * `token_ids` - The transferred tokens.
+///
+/// # Events
+///
+/// For each token, unless aggregated events are enabled:
+/// * topics - `["transfer", from: Address, to: Address]`
+/// * data - `[token_id: TokenId]`
+///
+/// If aggregated events are enabled:
+/// * topics - `["batch_transfer", from: Address, to: Address]`
+/// * data - `[token_ids: Vec<TokenId>]`
+pub fn emit_batch_transfer_events(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+    if aggregate_events(e) {
+        emit_batch_transfer(e, from, to, token_ids);
+    } else {
+        for token_id in token_ids.iter() {
+            emit_transfer(e, from, to, token_id);
+        }
+    }
+}
+
+/// Transfers every token of `token_ids` with `update`, which must handle the
+/// bookkeeping of the contract type, after checking the approval of
+/// `spender` (if any). Authorization is handled by the caller.
+fn transfer_batch(
+    e: &Env,
+    spender: Option<&Address>,
+    from: &Address,
+    to: &Address,
+    token_ids: &Vec<TokenId>,
+    update: fn(&Env, &Address, &Address, TokenId),
+) {
+    check_batch_size(e, token_ids);
+
+    for token_id in token_ids.iter() {
+        if let Some(spender) = spender {
+            Base::check_spender_approval(e, spender, from, token_id);
+        }
+        update(e, from, to, token_id);
+    }
+
+    emit_batch_transfer_events(e, from, to, token_ids);
+}
+
+/// Approves `approved` for every token of `token_ids`, resolving the owner of
+/// each token with `owner_of`. Authorization is handled by the caller.
+fn approve_batch(
+    e: &Env,
+    approver: &Address,
+    approved: &Address,
+    token_ids: &Vec<TokenId>,
+    live_until_ledger: u32,
+    owner_of: fn(&Env, TokenId) -> Address,
+) {
+    check_batch_size(e, token_ids);
+
+    for token_id in token_ids.iter() {
+        let owner = owner_of(e, token_id);
+        Base::approve_for_owner(e, &owner, approver, approved, token_id, live_until_ledger);
+    }
+}
+
+impl Base {
+    /// Transfers the `token_ids` tokens, ensuring ownership checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        from.require_auth();
+
+        transfer_batch(e, None, from, to, token_ids, |e, from, to, token_id| {
+            Base::update(e, Some(from), Some(to), token_id);
+        });
+    }
+
+    /// Transfers the `token_ids` tokens, ensuring ownership and approval
+    /// checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the tokens.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Base::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        spender.require_auth();
+
+        transfer_batch(e, Some(spender), from, to, token_ids, |e, from, to, token_id| {
+            Base::update(e, Some(from), Some(to), token_id);
+        });
+    }
+
+    /// Approves an address to transfer each of the `token_ids` tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address of the approver (should be `owner` or
+    ///   `operator`).
+    /// * `approved` - The address receiving the approval.
+    /// * `token_ids` - The identifiers of the tokens to be approved.
+    /// * `live_until_ledger` - The ledger number at which the approvals
+    ///   expire.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::approve_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `approver` is required.
+    pub fn batch_approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_ids: &Vec<TokenId>,
+        live_until_ledger: u32,
+    ) {
+        approver.require_auth();
+
+        approve_batch(e, approver, approved, token_ids, live_until_ledger, Base::owner_of);
+    }
+}
+
+impl Enumerable {
+    /// Transfers the `token_ids` tokens, ensuring ownership checks, and
+    /// moves them from the list of `from` to the list of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::batch_transfer`] errors.
+    /// * refer to [`Enumerable::remove_from_owner_enumeration`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        from.require_auth();
+
+        transfer_batch(e, None, from, to, token_ids, Enumerable::update_for_batch);
+    }
+
+    /// Transfers the `token_ids` tokens, ensuring ownership and approval
+    /// checks, and moves them from the list of `from` to the list of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the tokens.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::batch_transfer_from`] errors.
+    /// * refer to [`Enumerable::remove_from_owner_enumeration`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        spender.require_auth();
+
+        transfer_batch(e, Some(spender), from, to, token_ids, Enumerable::update_for_batch);
+    }
+
+    fn update_for_batch(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Base::update(e, Some(from), Some(to), token_id);
+
+        Enumerable::remove_from_owner_enumeration(e, from, token_id);
+        Enumerable::add_to_owner_enumeration(e, to, token_id);
+    }
+}
+
+impl Consecutive {
+    /// Transfers the `token_ids` tokens, ensuring ownership checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`Consecutive::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        from.require_auth();
+
+        transfer_batch(e, None, from, to, token_ids, |e, from, to, token_id| {
+            Consecutive::update(e, Some(from), Some(to), token_id);
+        });
+    }
+
+    /// Transfers the `token_ids` tokens, ensuring ownership and approval
+    /// checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the tokens.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`Consecutive::update`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        spender.require_auth();
+
+        transfer_batch(e, Some(spender), from, to, token_ids, |e, from, to, token_id| {
+            Consecutive::update(e, Some(from), Some(to), token_id);
+        });
+    }
+
+    /// Approves an address to transfer each of the `token_ids` tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address of the approver (should be `owner` or
+    ///   `operator`).
+    /// * `approved` - The address receiving the approval.
+    /// * `token_ids` - The identifiers of the tokens to be approved.
+    /// * `live_until_ledger` - The ledger number at which the approvals
+    ///   expire.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`Consecutive::owner_of`] errors.
+    /// * refer to [`Base::approve_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each token:
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `approver` is required.
+    pub fn batch_approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_ids: &Vec<TokenId>,
+        live_until_ledger: u32,
+    ) {
+        approver.require_auth();
+
+        approve_batch(e, approver, approved, token_ids, live_until_ledger, Consecutive::owner_of);
+    }
+}
+
+impl EnumerableConsecutive {
+    /// Transfers the `token_ids` tokens, ensuring ownership checks, and
+    /// moves them from the list of `from` to the list of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`EnumerableConsecutive::move_token`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        from.require_auth();
+
+        transfer_batch(e, None, from, to, token_ids, EnumerableConsecutive::move_token);
+    }
+
+    /// Transfers the `token_ids` tokens, ensuring ownership and approval
+    /// checks, and moves them from the list of `from` to the list of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the tokens.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_ids` - The identifiers of the tokens being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`check_batch_size`] errors.
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * refer to [`EnumerableConsecutive::move_token`] errors.
+    ///
+    /// # Events
+    ///
+    /// * refer to [`emit_batch_transfer_events`].
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        spender.require_auth();
+
+        transfer_batch(e, Some(spender), from, to, token_ids, EnumerableConsecutive::move_token);
+    }
+}
+
+impl Soulbound {
+    /// Soulbound tokens cannot be transferred, this function always panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `_from` - Account of the sender.
+    /// * `_to` - Account of the recipient.
+    /// * `_token_ids` - The tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonTransferableToken`] - Always.
+    pub fn batch_transfer(e: &Env, _from: &Address, _to: &Address, _token_ids: &Vec<TokenId>) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferableToken);
+    }
+
+    /// Soulbound tokens cannot be transferred, this function always panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `_spender` - The address authorizing the transfer.
+    /// * `_from` - Account of the sender.
+    /// * `_to` - Account of the recipient.
+    /// * `_token_ids` - The tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonTransferableToken`] - Always.
+    pub fn batch_transfer_from(
+        e: &Env,
+        _spender: &Address,
+        _from: &Address,
+        _to: &Address,
+        _token_ids: &Vec<TokenId>,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferableToken);
+    }
+
+    /// Soulbound tokens cannot be approved, this function always panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `_approver` - The address of the approver.
+    /// * `_approved` - The address receiving the approval.
+    /// * `_token_ids` - The tokens to approve.
+    /// * `_live_until_ledger` - The ledger number at which the approvals
+    ///   expire.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonTransferableToken`] - Always.
+    pub fn batch_approve(
+        e: &Env,
+        _approver: &Address,
+        _approved: &Address,
+        _token_ids: &Vec<TokenId>,
+        _live_until_ledger: u32,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferableToken);
+    }
+}
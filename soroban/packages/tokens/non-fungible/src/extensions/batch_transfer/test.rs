@@ -0,0 +1,434 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env, Vec};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    batch_transfer::{
+        storage::{aggregate_events, set_aggregate_events},
+        MAX_BATCH_TRANSFER_SIZE,
+    },
+    consecutive::Consecutive,
+    enumerable::Enumerable,
+    enumerable_consecutive::EnumerableConsecutive,
+    soulbound::Soulbound,
+    Base, ContractOverrides, TokenId,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        for _ in 0..4 {
+            Base::sequential_mint(&e, &owner);
+        }
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer(&e, &owner, &recipient, &vec![&e, 0, 2, 3]);
+
+        assert_eq!(Base::balance(&e, &owner), 1);
+        assert_eq!(Base::balance(&e, &recipient), 3);
+        assert_eq!(Base::owner_of(&e, 1), owner);
+        assert_eq!(Base::owner_of(&e, 2), recipient);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_non_fungible_transfer(&owner, &recipient, 0);
+        event_assert.assert_non_fungible_transfer(&owner, &recipient, 2);
+        event_assert.assert_non_fungible_transfer(&owner, &recipient, 3);
+    });
+}
+
+#[test]
+fn batch_transfer_with_aggregate_events_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert!(!aggregate_events(&e));
+        set_aggregate_events(&e, true);
+        assert!(aggregate_events(&e));
+
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+    });
+
+    e.as_contract(&address, || {
+        let token_ids = vec![&e, 1, 0];
+        Base::batch_transfer(&e, &owner, &recipient, &token_ids);
+
+        assert_eq!(Base::balance(&e, &recipient), 2);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_batch_transfer(&owner, &recipient, &token_ids);
+    });
+}
+
+#[test]
+fn batch_transfer_from_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_transfer_from(&e, &operator, &owner, &recipient, &vec![&e, 0, 1]);
+
+        assert_eq!(Base::balance(&e, &owner), 0);
+        assert_eq!(Base::balance(&e, &recipient), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn batch_transfer_from_without_approval_of_every_token_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &spender, 0, 1000);
+
+        Base::batch_transfer_from(&e, &spender, &owner, &recipient, &vec![&e, 0, 1]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn batch_transfer_with_a_token_of_another_owner_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &other);
+
+        Base::batch_transfer(&e, &owner, &recipient, &vec![&e, 0, 1]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn batch_transfer_empty_batch_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::batch_transfer(&e, &owner, &recipient, &vec![&e]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn batch_transfer_above_max_size_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let mut token_ids = Vec::new(&e);
+        for _ in 0..=MAX_BATCH_TRANSFER_SIZE {
+            token_ids.push_back(Base::sequential_mint(&e, &owner));
+        }
+        Base::batch_transfer(&e, &owner, &recipient, &token_ids);
+    });
+}
+
+#[test]
+fn enumerable_batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        for _ in 0..4 {
+            Enumerable::sequential_mint(&e, &owner);
+        }
+        Enumerable::sequential_mint(&e, &recipient);
+    });
+
+    e.as_contract(&address, || {
+        <Enumerable as ContractOverrides>::batch_transfer(&e, &owner, &recipient, &vec![&e, 1, 3]);
+
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner, 0, 10), vec![&e, 0, 2]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &recipient, 0, 10), vec![&e, 4, 1, 3]);
+        assert_eq!(Enumerable::total_supply(&e), 5);
+    });
+}
+
+#[test]
+fn enumerable_batch_transfer_from_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        for _ in 0..3 {
+            Enumerable::sequential_mint(&e, &owner);
+        }
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        <Enumerable as ContractOverrides>::batch_transfer_from(
+            &e,
+            &operator,
+            &owner,
+            &recipient,
+            &vec![&e, 0, 2],
+        );
+
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner, 0, 10), vec![&e, 1]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &recipient, 0, 10), vec![&e, 0, 2]);
+    });
+}
+
+#[test]
+fn consecutive_batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+    });
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::batch_transfer(
+            &e,
+            &owner,
+            &recipient,
+            &vec![&e, 3, 4, 8],
+        );
+
+        assert_eq!(Base::balance(&e, &owner), 7);
+        assert_eq!(Base::balance(&e, &recipient), 3);
+        for token_id in [0, 1, 2, 5, 6, 7, 9] {
+            assert_eq!(Consecutive::owner_of(&e, token_id), owner);
+        }
+        for token_id in [3, 4, 8] {
+            assert_eq!(Consecutive::owner_of(&e, token_id), recipient);
+        }
+    });
+}
+
+#[test]
+fn consecutive_batch_transfer_from_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 5);
+        Consecutive::batch_approve(&e, &owner, &spender, &vec![&e, 1, 2], 1000);
+    });
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::batch_transfer_from(
+            &e,
+            &spender,
+            &owner,
+            &recipient,
+            &vec![&e, 1, 2],
+        );
+
+        assert_eq!(Consecutive::owner_of(&e, 0), owner);
+        assert_eq!(Consecutive::owner_of(&e, 1), recipient);
+        assert_eq!(Consecutive::owner_of(&e, 2), recipient);
+        assert_eq!(Consecutive::owner_of(&e, 3), owner);
+        assert_eq!(Consecutive::get_approved(&e, 1), None);
+        assert_eq!(Consecutive::get_approved(&e, 2), None);
+    });
+}
+
+#[test]
+fn enumerable_consecutive_batch_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 5);
+    });
+
+    e.as_contract(&address, || {
+        <EnumerableConsecutive as ContractOverrides>::batch_transfer(
+            &e,
+            &owner,
+            &recipient,
+            &vec![&e, 1, 2],
+        );
+
+        let owner_tokens: std::vec::Vec<TokenId> = (0..Base::balance(&e, &owner))
+            .map(|index| EnumerableConsecutive::get_owner_token_id(&e, &owner, index))
+            .collect();
+        let recipient_tokens: std::vec::Vec<TokenId> = (0..Base::balance(&e, &recipient))
+            .map(|index| EnumerableConsecutive::get_owner_token_id(&e, &recipient, index))
+            .collect();
+        assert_eq!(owner_tokens, [0, 4, 3]);
+        assert_eq!(recipient_tokens, [1, 2]);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 3), owner);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn soulbound_batch_transfer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+
+        <Soulbound as ContractOverrides>::batch_transfer(&e, &owner, &recipient, &vec![&e, 0]);
+    });
+}
+
+#[test]
+fn batch_approve_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        for _ in 0..3 {
+            Base::sequential_mint(&e, &owner);
+        }
+    });
+
+    e.as_contract(&address, || {
+        Base::batch_approve(&e, &owner, &approved, &vec![&e, 0, 2], 1000);
+
+        assert_eq!(Base::get_approved(&e, 0), Some(approved.clone()));
+        assert_eq!(Base::get_approved(&e, 1), None);
+        assert_eq!(Base::get_approved(&e, 2), Some(approved.clone()));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_non_fungible_approve(&owner, &approved, 0, 1000);
+        event_assert.assert_non_fungible_approve(&owner, &approved, 2, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn batch_approve_with_a_token_of_another_owner_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &other);
+
+        Base::batch_approve(&e, &owner, &approved, &vec![&e, 0, 1], 1000);
+    });
+}
+
+#[test]
+fn consecutive_batch_approve_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 5);
+    });
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::batch_approve(
+            &e,
+            &owner,
+            &approved,
+            &vec![&e, 2, 4],
+            1000,
+        );
+        <Consecutive as ContractOverrides>::batch_transfer_from(
+            &e,
+            &approved,
+            &owner,
+            &recipient,
+            &vec![&e, 2, 4],
+        );
+
+        assert_eq!(Consecutive::owner_of(&e, 2), recipient);
+        assert_eq!(Consecutive::owner_of(&e, 3), owner);
+        assert_eq!(Consecutive::owner_of(&e, 4), recipient);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn soulbound_batch_approve_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &owner);
+
+        <Soulbound as ContractOverrides>::batch_approve(&e, &owner, &approved, &vec![&e, 0], 1000);
+    });
+}
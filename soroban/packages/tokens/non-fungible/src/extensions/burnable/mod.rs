@@ -1,5 +1,5 @@
 mod storage;
-use crate::{Base, NonFungibleToken, TokenId};
+use crate::{NonFungibleToken, TokenId};
 
 mod test;
 
@@ -16,6 +16,12 @@ use soroban_sdk::{symbol_short, Address, Env};
 /// choice to accommodate flexibility and customization for various smart
 /// contract use cases.
 ///
+/// The tokens are burned with the logic of the `ContractType` of the
+/// [`crate::NonFungibleToken`] implementation (see
+/// [`crate::ContractOverrides::burn`]), so this trait is compatible with
+/// [`crate::Base`], [`crate::enumerable::Enumerable`],
+/// [`crate::consecutive::Consecutive`] and [`crate::soulbound::Soulbound`].
+///
 /// # Notes
 ///
 /// `#[contractimpl]` macro requires even the default implementations to be
@@ -23,7 +29,7 @@ use soroban_sdk::{symbol_short, Address, Env};
 /// the default implementations here, but we are providing a macro to generate
 /// the default implementations for you.
 ///
-/// When implementing [`NonFungibleBunrable`] trait for your Smart Contract,
+/// When implementing [`NonFungibleBurnable`] trait for your Smart Contract,
 /// you can follow the below example:
 ///
 /// ```ignore
@@ -34,7 +40,7 @@ use soroban_sdk::{symbol_short, Address, Env};
 ///     /* and the macro will generate all the missing default implementations for you */
 /// }
 /// ```
-pub trait NonFungibleBurnable: NonFungibleToken<ContractType = Base> {
+pub trait NonFungibleBurnable: NonFungibleToken {
     /// Destroys the `token_id` from `account`.
     ///
     /// # Arguments
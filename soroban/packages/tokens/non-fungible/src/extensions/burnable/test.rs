@@ -5,7 +5,9 @@ extern crate std;
 use soroban_sdk::{contract, testutils::Address as _, Address, Env};
 use stellar_event_assertion::EventAssertion;
 
-use crate::Base;
+use crate::{
+    consecutive::Consecutive, enumerable::Enumerable, soulbound::Soulbound, Base, ContractOverrides,
+};
 
 #[contract]
 struct MockContract;
@@ -148,3 +150,95 @@ fn burn_with_non_existent_token_panics() {
         Base::burn(&e, &owner, non_existent_token_id);
     });
 }
+
+#[test]
+fn burn_dispatches_to_enumerable_sequential() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id1 = Enumerable::sequential_mint(&e, &owner);
+        let token_id2 = Enumerable::sequential_mint(&e, &owner);
+
+        <Enumerable as ContractOverrides>::burn(&e, &owner, token_id1);
+
+        assert_eq!(Base::balance(&e, &owner), 1);
+        assert_eq!(Enumerable::total_supply(&e), 1);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &owner, 0), token_id2);
+    });
+}
+
+#[test]
+fn burn_from_dispatches_to_enumerable_non_sequential() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Enumerable::non_sequential_mint(&e, &owner, 42);
+        Enumerable::non_sequential_mint(&e, &owner, 83);
+        Base::approve(&e, &owner, &spender, 42, 1000);
+
+        <Enumerable as ContractOverrides>::burn_from(&e, &spender, &owner, 42);
+
+        assert_eq!(Base::balance(&e, &owner), 1);
+        assert_eq!(Enumerable::total_supply(&e), 1);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &owner, 0), 83);
+        assert_eq!(Enumerable::get_token_id(&e, 0), 83);
+    });
+}
+
+#[test]
+fn burn_dispatches_to_consecutive() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 3);
+
+        <Consecutive as ContractOverrides>::burn(&e, &owner, 1);
+
+        assert_eq!(Base::balance(&e, &owner), 2);
+        assert_eq!(Consecutive::owner_of(&e, 0), owner);
+        assert_eq!(Consecutive::owner_of(&e, 2), owner);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn burned_consecutive_token_has_no_owner() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 3);
+
+        <Consecutive as ContractOverrides>::burn(&e, &owner, 1);
+
+        Consecutive::owner_of(&e, 1);
+    });
+}
+
+#[test]
+fn burn_dispatches_to_base_for_soulbound() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        <Soulbound as ContractOverrides>::burn(&e, &owner, token_id);
+
+        assert_eq!(Base::balance(&e, &owner), 0);
+    });
+}
@@ -0,0 +1,26 @@
+/// Unlike other extensions, the `capped` extension does not provide a separate
+/// trait. This is because its methods are not intended to be used
+/// independently, like [`crate::extensions::burnable::NonFungibleBurnable`].
+/// Instead, once a cap is set, the minting functions of this crate enforce it
+/// automatically:
+/// - [`crate::Base::sequential_mint`] and [`crate::Base::mint`],
+/// - [`crate::enumerable::Enumerable::sequential_mint`] and
+///   [`crate::enumerable::Enumerable::non_sequential_mint`],
+/// - [`crate::consecutive::Consecutive::batch_mint`].
+///
+/// The cap limits the number of tokens that can ever be minted, including the
+/// ones minted before the cap was set. Burning a token does NOT free up room
+/// under the cap.
+///
+/// This module provides the following helper functions:
+/// - `set_cap`: Sets the maximum token supply.
+/// - `query_cap`: Returns the maximum token supply.
+/// - `total_minted`: Returns the number of tokens ever minted.
+/// - `check_cap`: Panics if minting a specified `amount` would exceed the cap.
+/// - `record_mint`: Checks the cap and accounts for `amount` minted tokens.
+///   Only needed when minting through the low-level [`crate::Base::update`].
+mod storage;
+pub use self::storage::{
+    check_cap, query_cap, record_mint, set_cap, total_minted, CAP_KEY, MINTED_KEY,
+};
+mod test;
@@ -0,0 +1,107 @@
+use soroban_sdk::{panic_with_error, symbol_short, Env, Symbol};
+
+use crate::{Balance, NonFungibleTokenError};
+
+/// Storage key for the maximum supply.
+pub const CAP_KEY: Symbol = symbol_short!("CAP");
+/// Storage key for the number of tokens ever minted.
+pub const MINTED_KEY: Symbol = symbol_short!("MINTED");
+
+/// Set the maximum supply of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `cap` - The maximum number of tokens that can be minted.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::InvalidCap`] - Occurs when the provided cap is
+///   lower than the number of tokens already minted.
+///
+/// # Notes
+///
+/// * We recommend using this function in the constructor of your smart
+///   contract.
+/// * Tokens minted before the cap is set are counted against it.
+pub fn set_cap(e: &Env, cap: Balance) {
+    if cap < total_minted(e) {
+        panic_with_error!(e, NonFungibleTokenError::InvalidCap);
+    }
+    e.storage().instance().set(&CAP_KEY, &cap);
+}
+
+/// Returns the maximum supply of tokens.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::CapNotSet`] - Occurs when the cap has not been
+///   set.
+pub fn query_cap(e: &Env) -> Balance {
+    e.storage()
+        .instance()
+        .get(&CAP_KEY)
+        .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::CapNotSet))
+}
+
+/// Returns the number of tokens ever minted, whether or not a cap was set at
+/// the time. Burned tokens are still counted.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn total_minted(e: &Env) -> Balance {
+    e.storage().instance().get(&MINTED_KEY).unwrap_or(0)
+}
+
+/// Panics if minting `amount` new tokens will exceed the maximum supply.
+/// Does nothing if no cap is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `amount` - The number of tokens to be minted.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::ExceededCap`] - Occurs when the new amount of
+///   tokens will exceed the cap.
+pub fn check_cap(e: &Env, amount: Balance) {
+    let Some(cap) = e.storage().instance().get::<_, Balance>(&CAP_KEY) else {
+        return;
+    };
+    match total_minted(e).checked_add(amount) {
+        Some(minted) if minted <= cap => {}
+        _ => panic_with_error!(e, NonFungibleTokenError::ExceededCap),
+    }
+}
+
+/// Low-level function that checks the cap and accounts for `amount` newly
+/// minted tokens. Mints are accounted for even if no cap is set, so that a
+/// cap set later on also covers them.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `amount` - The number of tokens to be minted.
+///
+/// # Errors
+///
+/// * refer to [`check_cap`] errors.
+///
+/// # Notes
+///
+/// This function is already called by the minting functions of this crate,
+/// and must be called before any other storage update. Call it manually only
+/// when minting through [`crate::Base::update`].
+pub fn record_mint(e: &Env, amount: Balance) {
+    check_cap(e, amount);
+    let Some(minted) = total_minted(e).checked_add(amount) else {
+        panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+    };
+    e.storage().instance().set(&MINTED_KEY, &minted);
+}
@@ -0,0 +1,244 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+use crate::{
+    capped::{check_cap, query_cap, record_mint, set_cap, total_minted},
+    consecutive::Consecutive,
+    enumerable::Enumerable,
+    Base,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn test_query_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_cap(&e, 100);
+
+        assert_eq!(query_cap(&e), 100);
+        assert_eq!(total_minted(&e), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #312)")]
+fn test_query_unset_cap_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        query_cap(&e);
+    });
+}
+
+#[test]
+fn test_mint_without_cap_is_unlimited() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        check_cap(&e, 1_000);
+        record_mint(&e, 1_000);
+        Base::sequential_mint(&e, &user);
+
+        assert_eq!(total_minted(&e), 1_001);
+        assert_eq!(Base::balance(&e, &user), 1);
+    });
+}
+
+#[test]
+fn test_mint_before_cap_is_counted() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &user);
+        Consecutive::batch_mint(&e, &user, 3);
+        set_cap(&e, 5);
+
+        assert_eq!(total_minted(&e), 4);
+        Base::sequential_mint(&e, &user);
+        assert_eq!(total_minted(&e), 5);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_mint_before_cap_exceeds_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &user, 4);
+        set_cap(&e, 4);
+
+        Base::sequential_mint(&e, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #314)")]
+fn test_set_cap_below_mint_before_cap_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &user, 5);
+
+        set_cap(&e, 4);
+    });
+}
+
+#[test]
+fn test_sequential_mint_exact_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 2);
+
+        Base::sequential_mint(&e, &user);
+        Base::sequential_mint(&e, &user);
+
+        assert_eq!(total_minted(&e), 2);
+        assert_eq!(Base::balance(&e, &user), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_sequential_mint_exceeds_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 2);
+
+        Base::sequential_mint(&e, &user);
+        Base::sequential_mint(&e, &user);
+        Base::sequential_mint(&e, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_mint_exceeds_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 1);
+
+        Base::mint(&e, &user, 42);
+        Base::mint(&e, &user, 7);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_burn_does_not_free_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 1);
+
+        let token_id = Base::sequential_mint(&e, &user);
+        Base::burn(&e, &user, token_id);
+
+        Base::sequential_mint(&e, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_enumerable_sequential_mint_exceeds_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 1);
+
+        Enumerable::sequential_mint(&e, &user);
+        Enumerable::sequential_mint(&e, &user);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_enumerable_non_sequential_mint_exceeds_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 1);
+
+        Enumerable::non_sequential_mint(&e, &user, 10);
+        Enumerable::non_sequential_mint(&e, &user, 20);
+    });
+}
+
+#[test]
+fn test_consecutive_batch_mint_exact_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 10);
+
+        Consecutive::batch_mint(&e, &user, 6);
+        Consecutive::batch_mint(&e, &user, 4);
+
+        assert_eq!(total_minted(&e), 10);
+        assert_eq!(Base::balance(&e, &user), 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #313)")]
+fn test_consecutive_batch_mint_exceeds_cap() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 10);
+
+        Consecutive::batch_mint(&e, &user, 6);
+        Consecutive::batch_mint(&e, &user, 5);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #314)")]
+fn test_set_cap_below_minted_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_cap(&e, 10);
+        Consecutive::batch_mint(&e, &user, 5);
+
+        set_cap(&e, 4);
+    });
+}
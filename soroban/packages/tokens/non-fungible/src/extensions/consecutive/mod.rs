@@ -11,9 +11,16 @@
 //! ## Implementation Notes
 //!
 //! - **Minting**: `batch_mint` stores the owner only for the first token ID in
-//!   the batch.
-//! - **owner_of**: Walks backwards from the token ID to find the closest
-//!   recorded owner.
+//!   the batch, along with a checkpoint of the batch (its first token ID and
+//!   its recipient) in an indexed list.
+//! - **owner_of**: Returns the recorded owner of the token ID if there is one,
+//!   otherwise binary searches the batch checkpoints, i.e. takes `O(log n)`
+//!   storage reads for `n` minted batches.
+//! - **Migration**: Batches minted before the checkpoints were introduced have
+//!   none, and `owner_of` panics for their tokens, instead of walking back the
+//!   token IDs in `O(n)` storage reads. After upgrading, their checkpoints
+//!   must be recorded once with `migrate_batch_checkpoints`, from the
+//!   `consecutive_mint` events, before minting new batches.
 //! - **Transfer**: Stores the new owner for the token ID and re-stores the old
 //!   owner at `token_id + 1` if needed, to preserve correct inference for later
 //!   tokens.
@@ -22,9 +29,10 @@
 //!
 //! ## Caveats
 //!
-//! - Slightly more expensive reads due to reverse scan in `owner_of`. Please
-//!   note that after Protocol 23 the cost of storage reads will be marginal, so
-//!   the overhead of this approach will be minimal.
+//! - Slightly more expensive reads due to the binary search in `owner_of`.
+//! - The number of tokens minted with a single `batch_mint` is limited by a
+//!   configurable max. batch size (see [`Consecutive::set_max_batch_size`]),
+//!   which defaults to [`DEFAULT_MAX_BATCH_SIZE`].
 //! - Requires extra logic to preserve ownership inference when transferring or
 //!   burning tokens.
 //!
@@ -33,14 +41,18 @@
 //! - It is not recommended to use this model if each token is expected to be
 //!   minted separately. It is rather best suited for NFTs where minting happens
 //!   in large batches.
-//! - **IMPORTANT** - For minting tokens ONLY the function `batch_mint` provided
-//!   in this extension must be used. Using other minting functions will break
-//!   the logic of tracking ownership.
+//! - **IMPORTANT** - For minting tokens ONLY the functions `batch_mint` and
+//!   `batch_mint_to_many` provided in this extension must be used. Using other
+//!   minting functions will break the logic of tracking ownership.
 pub mod storage;
 use soroban_sdk::{Address, Env, Symbol};
 pub use storage::Consecutive;
 
-use crate::{NonFungibleToken, TokenId};
+use crate::{Balance, NonFungibleToken, TokenId};
+
+/// Default max. number of tokens minted with a single call to
+/// [`Consecutive::batch_mint`].
+pub const DEFAULT_MAX_BATCH_SIZE: Balance = 10_000;
 
 /// Consecutive Marker Trait for Non-Fungible Token
 ///
@@ -48,7 +60,9 @@ use crate::{NonFungibleToken, TokenId};
 ///
 /// The `consecutive` extension provides its own business logic for creating and
 /// destroying tokens. Therefore, this trait is INCOMPATIBLE with the
-/// `Mintable`, `Burnable`, and `Enumerable` extensions.
+/// `Mintable` and `Enumerable` extensions. For enumerable collections minted
+/// in batches, use [`crate::enumerable_consecutive`] instead. `Burnable` burns the tokens with
+/// [`Consecutive::burn`] and [`Consecutive::burn_from`].
 pub trait NonFungibleConsecutive: NonFungibleToken<ContractType = Consecutive> {}
 
 mod test;
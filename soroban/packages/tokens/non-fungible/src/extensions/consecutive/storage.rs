@@ -1,14 +1,16 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String, Vec};
 use stellar_constants::{
     OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
 };
 
 use crate::{
     burnable::emit_burn,
+    capped::record_mint,
     emit_transfer,
-    extensions::consecutive::emit_consecutive_mint,
+    extensions::consecutive::{emit_consecutive_mint, DEFAULT_MAX_BATCH_SIZE},
     sequential::{self as sequential},
-    Balance, Base, ContractOverrides, NonFungibleTokenError, TokenId,
+    uri_storage, ApprovalData, Balance, Base, ContractOverrides, NonFungibleTokenError, TokenId,
+    MAX_BATCH_MINT_RECIPIENTS,
 };
 
 pub struct Consecutive;
@@ -30,6 +32,38 @@ impl ContractOverrides for Consecutive {
         Consecutive::transfer_from(e, spender, from, to, token_id);
     }
 
+    fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        Consecutive::batch_transfer(e, from, to, token_ids);
+    }
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        Consecutive::batch_transfer_from(e, spender, from, to, token_ids);
+    }
+
+    fn batch_approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_ids: &Vec<TokenId>,
+        live_until_ledger: u32,
+    ) {
+        Consecutive::batch_approve(e, approver, approved, token_ids, live_until_ledger);
+    }
+
+    fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Consecutive::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Consecutive::burn_from(e, spender, from, token_id);
+    }
+
     fn approve(
         e: &Env,
         approver: &Address,
@@ -39,6 +73,27 @@ impl ContractOverrides for Consecutive {
     ) {
         Consecutive::approve(e, approver, approved, token_id, live_until_ledger);
     }
+
+    fn approval_of(e: &Env, token_id: TokenId) -> Option<ApprovalData> {
+        Consecutive::approval_of(e, token_id)
+    }
+
+    fn revoke_approval(e: &Env, approver: &Address, token_id: TokenId) {
+        Consecutive::revoke_approval(e, approver, token_id);
+    }
+
+    fn set_user(e: &Env, caller: &Address, token_id: TokenId, user: &Address, expires_ledger: u32) {
+        Consecutive::set_user(e, caller, token_id, user, expires_ledger);
+    }
+}
+
+/// Checkpoint recorded for every batch minted with
+/// [`Consecutive::batch_mint`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchCheckpoint {
+    pub first_token_id: TokenId,
+    pub owner: Address,
 }
 
 /// Storage keys for the data associated with `FungibleToken`
@@ -47,6 +102,9 @@ pub enum StorageKey {
     Approval(TokenId),
     Owner(TokenId),
     BurnedToken(TokenId),
+    MaxBatchSize,
+    BatchCount,
+    Batch(u32),
 }
 
 impl Consecutive {
@@ -62,7 +120,9 @@ impl Consecutive {
     /// # Errors
     ///
     /// * [`NonFungibleTokenError::NonExistentToken`] - Occurs if the provided
-    ///   `token_id` does not exist.
+    ///   `token_id` does not exist, or if it was minted before the batch
+    ///   checkpoints were introduced and its batch is not migrated yet (see
+    ///   [`Consecutive::migrate_batch_checkpoints`]).
     pub fn owner_of(e: &Env, token_id: TokenId) -> Address {
         let max = sequential::next_token_id(e);
         let key = StorageKey::BurnedToken(token_id);
@@ -75,24 +135,73 @@ impl Consecutive {
             panic_with_error!(&e, NonFungibleTokenError::NonExistentToken);
         }
 
-        (0..=token_id)
-            .rev()
-            .map(StorageKey::Owner)
-            // after the Protocol 23 upgrade, storage read cost is marginal,
-            // making the consecutive storage reads justifiable
-            .find_map(|key| {
-                e.storage().persistent().get::<_, Address>(&key).inspect(|_| {
-                    e.storage().persistent().extend_ttl(
-                        &key,
-                        OWNER_TTL_THRESHOLD,
-                        OWNER_EXTEND_AMOUNT,
-                    );
-                })
-            })
-            .unwrap_or_else(|| panic_with_error!(&e, NonFungibleTokenError::NonExistentToken))
+        let owner_key = StorageKey::Owner(token_id);
+        if let Some(owner) = e.storage().persistent().get::<_, Address>(&owner_key) {
+            e.storage().persistent().extend_ttl(
+                &owner_key,
+                OWNER_TTL_THRESHOLD,
+                OWNER_EXTEND_AMOUNT,
+            );
+            return owner;
+        }
+
+        // tokens without a recorded owner have never left the batch they were
+        // minted in, so they belong to the recipient of that batch
+        match Consecutive::batch_of(e, token_id) {
+            Some(batch) => batch.owner,
+            // minted before the checkpoints were introduced, and not migrated
+            // yet (see `Consecutive::migrate_batch_checkpoints`)
+            None => panic_with_error!(&e, NonFungibleTokenError::NonExistentToken),
+        }
+    }
+
+    /// Returns the checkpoint of the batch the `token_id` was minted in, or
+    /// `None` if the token was not minted with [`Consecutive::batch_mint`].
+    /// The checkpoints are binary searched, so the lookup takes `O(log n)`
+    /// storage reads, `n` being the number of minted batches.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    pub fn batch_of(e: &Env, token_id: TokenId) -> Option<BatchCheckpoint> {
+        let count: u32 = e.storage().instance().get(&StorageKey::BatchCount).unwrap_or(0);
+
+        // find the last batch starting at or before `token_id`
+        let (mut low, mut high) = (0, count);
+        let mut found = None;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let key = StorageKey::Batch(mid);
+            let batch: BatchCheckpoint = e.storage().persistent().get(&key)?;
+            e.storage().persistent().extend_ttl(&key, OWNER_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT);
+
+            if batch.first_token_id <= token_id {
+                found = Some(batch);
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        found
+    }
+
+    /// Returns the max. number of tokens that can be minted with a single
+    /// call to [`Consecutive::batch_mint`]. Defaults to
+    /// [`DEFAULT_MAX_BATCH_SIZE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn max_batch_size(e: &Env) -> Balance {
+        e.storage().instance().get(&StorageKey::MaxBatchSize).unwrap_or(DEFAULT_MAX_BATCH_SIZE)
     }
 
-    /// Returns the URI for a specific `token_id`.
+    /// Returns the URI for a specific `token_id`. Until the collection is
+    /// revealed, the placeholder URI is returned if there is one (see
+    /// [`crate::reveal`]). If a URI is stored for the token (see
+    /// [`crate::uri_storage`]), it is returned as is, otherwise the URI is
+    /// composed from the base URI.
     ///
     /// # Arguments
     ///
@@ -105,12 +214,46 @@ impl Consecutive {
     /// * refer to [`base_uri`] errors.
     pub fn token_uri(e: &Env, token_id: TokenId) -> String {
         let _ = Consecutive::owner_of(e, token_id);
+        if let Some(uri) = Base::placeholder_uri(e) {
+            return uri;
+        }
+        if let Some(uri) = Base::stored_token_uri(e, token_id) {
+            return uri;
+        }
         let base_uri = Base::base_uri(e);
-        Base::compose_uri_for_token(e, base_uri, token_id)
+        Base::compose_uri_with_format(
+            e,
+            base_uri,
+            Base::revealed_token_id(e, token_id),
+            &Base::uri_format(e),
+        )
     }
 
     // ################## CHANGE STATE ##################
 
+    /// Sets the max. number of tokens that can be minted with a single call
+    /// to [`Consecutive::batch_mint`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `size` - The max. batch size.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidBatchSize`] - If `size` is `0`.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The function intentionally lacks authorization controls.
+    /// We recommend using it in the constructor of your smart contract.
+    pub fn set_max_batch_size(e: &Env, size: Balance) {
+        if size == 0 {
+            panic_with_error!(e, NonFungibleTokenError::InvalidBatchSize);
+        }
+        e.storage().instance().set(&StorageKey::MaxBatchSize, &size);
+    }
+
     /// Mints a batch of tokens with consecutive ids and attributes them to
     /// `to`. This function does NOT handle authorization.
     ///
@@ -122,6 +265,9 @@ impl Consecutive {
     ///
     /// # Errors
     ///
+    /// * [`NonFungibleTokenError::InvalidBatchSize`] - If `amount` is `0` or
+    ///   above [`Consecutive::max_batch_size`].
+    /// * refer to [`record_mint`] errors.
     /// * refer to [`Base::increase_balance`] errors.
     ///
     /// # Events
@@ -148,10 +294,20 @@ impl Consecutive {
     ///
     /// Failing to add proper authorization could allow anyone to mint tokens!
     pub fn batch_mint(e: &Env, to: &Address, amount: Balance) -> TokenId {
+        if amount == 0 || amount > Consecutive::max_batch_size(e) {
+            panic_with_error!(e, NonFungibleTokenError::InvalidBatchSize);
+        }
+
+        record_mint(e, amount);
         let first_id = sequential::increment_token_id(e, amount);
 
         e.storage().persistent().set(&StorageKey::Owner(first_id), &to);
 
+        Consecutive::push_batch_checkpoint(
+            e,
+            &BatchCheckpoint { first_token_id: first_id, owner: to.clone() },
+        );
+
         Base::increase_balance(e, to, amount);
 
         let last_id = first_id + amount - 1;
@@ -161,6 +317,93 @@ impl Consecutive {
         last_id
     }
 
+    /// Mints consecutive batches of tokens to multiple recipients, one batch
+    /// per `(recipient, amount)` pair, in the given order. Returns the last
+    /// minted `token_id`. This function does NOT handle authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recipients` - The recipients along with their number of tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidBatchSize`] - If `recipients` is
+    ///   empty or has more than [`MAX_BATCH_MINT_RECIPIENTS`] entries.
+    /// * refer to [`Consecutive::batch_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    /// * topics - `["consecutive_mint", to: Address]`
+    /// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The function intentionally lacks authorization controls.
+    /// You MUST invoke it only from the constructor or implement proper
+    /// authorization in the calling function (see
+    /// [`Consecutive::batch_mint`]).
+    pub fn batch_mint_to_many(e: &Env, recipients: &Vec<(Address, Balance)>) -> TokenId {
+        if recipients.is_empty() || recipients.len() > MAX_BATCH_MINT_RECIPIENTS {
+            panic_with_error!(e, NonFungibleTokenError::InvalidBatchSize);
+        }
+
+        let mut last_id = 0;
+        for (to, amount) in recipients.iter() {
+            last_id = Consecutive::batch_mint(e, &to, amount);
+        }
+        last_id
+    }
+
+    /// Records the checkpoints of batches minted before the checkpoints were
+    /// introduced, so that [`Consecutive::owner_of`] can resolve the owner of
+    /// their tokens. Until then, it panics for these tokens, rather than
+    /// walking back the tokens in `O(n)` storage reads.
+    ///
+    /// Meant to be called once after upgrading a contract, before minting
+    /// any new batch. It can be called several times to migrate the batches
+    /// in chunks, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `checkpoints` - The first `token_id` and the recipient of every
+    ///   batch, as emitted in their `consecutive_mint` events, in order.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidBatchCheckpoint`] - If the
+    ///   checkpoints are not strictly increasing, do not follow the recorded
+    ///   checkpoints, or refer to tokens that are not minted yet.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The function intentionally lacks authorization controls,
+    /// and trusts the given recipients. You want to invoke it from another
+    /// function with admin-only authorization, e.g. upon upgrade.
+    pub fn migrate_batch_checkpoints(e: &Env, checkpoints: &Vec<BatchCheckpoint>) {
+        let next_id = sequential::next_token_id(e);
+        let count: u32 = e.storage().instance().get(&StorageKey::BatchCount).unwrap_or(0);
+        let mut previous = match count {
+            0 => None,
+            _ => e
+                .storage()
+                .persistent()
+                .get::<_, BatchCheckpoint>(&StorageKey::Batch(count - 1))
+                .map(|checkpoint| checkpoint.first_token_id),
+        };
+
+        for checkpoint in checkpoints.iter() {
+            let first_id = checkpoint.first_token_id;
+            if first_id >= next_id || previous.is_some_and(|previous| first_id <= previous) {
+                panic_with_error!(e, NonFungibleTokenError::InvalidBatchCheckpoint);
+            }
+            Consecutive::push_batch_checkpoint(e, &checkpoint);
+            previous = Some(first_id);
+        }
+    }
+
     /// Destroys the `token_id` from `account`, ensuring ownership
     /// checks, and emits a `burn` event.
     ///
@@ -330,6 +573,45 @@ impl Consecutive {
         Base::approve_for_owner(e, &owner, approver, approved, token_id, live_until_ledger);
     }
 
+    /// Revokes the approval of `token_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address revoking the approval (should be `owner` or
+    ///   `operator`).
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::owner_of`] errors.
+    /// * refer to [`Base::revoke_approval_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// The event is emitted with `live_until_ledger` set to `0`, and only if
+    /// the token had an approval.
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `approver` is required.
+    pub fn revoke_approval(e: &Env, approver: &Address, token_id: TokenId) {
+        approver.require_auth();
+
+        let owner = Consecutive::owner_of(e, token_id);
+        Base::revoke_approval_for_owner(e, &owner, approver, token_id);
+    }
+
+    /// Appends `checkpoint` to the indexed list of batch checkpoints.
+    fn push_batch_checkpoint(e: &Env, checkpoint: &BatchCheckpoint) {
+        let count: u32 = e.storage().instance().get(&StorageKey::BatchCount).unwrap_or(0);
+        e.storage().persistent().set(&StorageKey::Batch(count), checkpoint);
+        e.storage().instance().set(&StorageKey::BatchCount, &(count + 1));
+    }
+
     /// Low-level function for handling transfers, mints and burns of an NFT,
     /// without handling authorization. Updates ownership records, adjusts
     /// balances, and clears existing approvals.
@@ -337,7 +619,8 @@ impl Consecutive {
     /// The difference with [`Base::update`] is that the
     /// current function:
     /// 1. explicitly adds burned tokens to storage in
-    ///    `StorageKey::BurnedToken`,
+    ///    `StorageKey::BurnedToken` (and clears their stored URI, as
+    ///    [`Base::update`] does),
     /// 2. sets the next token (if any) to the previous owner.
     ///
     /// # Arguments
@@ -369,6 +652,9 @@ impl Consecutive {
             let approval_key = StorageKey::Approval(token_id);
             e.storage().temporary().remove(&approval_key);
 
+            // Clear any existing user
+            Base::remove_user(e, token_id);
+
             // Set the next token to prev owner
             Consecutive::set_owner_for(e, from_address, token_id + 1);
         } else {
@@ -384,14 +670,16 @@ impl Consecutive {
         } else {
             // Burning: `to` is None
             e.storage().persistent().remove(&StorageKey::Owner(token_id));
+            e.storage().persistent().remove(&uri_storage::StorageKey::TokenUri(token_id));
 
             e.storage().persistent().set(&StorageKey::BurnedToken(token_id), &true);
         }
     }
 
     /// Low-level function that sets owner of `token_id` to `to`, without
-    /// handling authorization. The function does not panic and sets the
-    /// owner only if:
+    /// handling authorization. The recorded owner takes precedence over the
+    /// owner of the batch, for `token_id` only. The function does not panic
+    /// and sets the owner only if:
     /// - the token exists and
     /// - the token has not been burned and
     /// - the token doesn't have an owner.
@@ -2,18 +2,52 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env, Vec};
 use stellar_event_assertion::EventAssertion;
 
 use crate::{
-    consecutive::{storage::StorageKey, Consecutive},
-    sequential::next_token_id,
-    Base,
+    consecutive::{
+        storage::{BatchCheckpoint, StorageKey},
+        Consecutive, DEFAULT_MAX_BATCH_SIZE,
+    },
+    sequential::{increment_token_id, next_token_id},
+    Balance, Base, NonFungibleTokenError, TokenId, MAX_BATCH_MINT_RECIPIENTS,
 };
 
 #[contract]
 pub struct MockContract;
 
+#[contract]
+pub struct ConsecutiveContract;
+
+#[contractimpl]
+impl ConsecutiveContract {
+    pub fn batch_mint(e: &Env, to: Address, amount: Balance) -> TokenId {
+        Consecutive::batch_mint(e, &to, amount)
+    }
+
+    pub fn owner_of(e: &Env, token_id: TokenId) -> Address {
+        Consecutive::owner_of(e, token_id)
+    }
+
+    pub fn legacy_batch_mint(e: &Env, to: Address, amount: Balance) -> TokenId {
+        legacy_batch_mint(e, &to, amount)
+    }
+
+    pub fn migrate_batch_checkpoints(e: &Env, checkpoints: Vec<BatchCheckpoint>) {
+        Consecutive::migrate_batch_checkpoints(e, &checkpoints);
+    }
+}
+
+/// Mints a batch as it used to be before the checkpoints were introduced,
+/// and returns its first `token_id`.
+fn legacy_batch_mint(e: &Env, to: &Address, amount: Balance) -> TokenId {
+    let first_id = increment_token_id(e, amount);
+    e.storage().persistent().set(&StorageKey::Owner(first_id), to);
+    Base::increase_balance(e, to, amount);
+    first_id
+}
+
 #[test]
 fn consecutive_batch_mint_works() {
     let e = Env::default();
@@ -256,3 +290,319 @@ fn consecutive_set_owner_for_works() {
         assert_eq!(owner, None);
     });
 }
+
+#[test]
+fn consecutive_owner_of_across_batches_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &user1, 5); // 0..=4
+        Consecutive::batch_mint(&e, &user2, 3); // 5..=7
+        Consecutive::batch_mint(&e, &user1, 4); // 8..=11
+
+        Consecutive::transfer(&e, &user2, &user3, 6);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &user1, &user3, 0);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::burn(&e, &user1, 9);
+    });
+
+    e.as_contract(&address, || {
+        assert_eq!(Consecutive::owner_of(&e, 0), user3);
+        assert_eq!(Consecutive::owner_of(&e, 1), user1);
+        assert_eq!(Consecutive::owner_of(&e, 4), user1);
+        assert_eq!(Consecutive::owner_of(&e, 5), user2);
+        assert_eq!(Consecutive::owner_of(&e, 6), user3);
+        assert_eq!(Consecutive::owner_of(&e, 7), user2);
+        assert_eq!(Consecutive::owner_of(&e, 8), user1);
+        assert_eq!(Consecutive::owner_of(&e, 10), user1);
+        assert_eq!(Consecutive::owner_of(&e, 11), user1);
+
+        assert_eq!(
+            Consecutive::batch_of(&e, 7),
+            Some(BatchCheckpoint { first_token_id: 5, owner: user2.clone() })
+        );
+        assert_eq!(
+            Consecutive::batch_of(&e, 11),
+            Some(BatchCheckpoint { first_token_id: 8, owner: user1.clone() })
+        );
+    });
+}
+
+#[test]
+fn consecutive_max_batch_size_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(Consecutive::max_batch_size(&e), DEFAULT_MAX_BATCH_SIZE);
+
+        Consecutive::set_max_batch_size(&e, 5);
+        assert_eq!(Consecutive::max_batch_size(&e), 5);
+
+        assert_eq!(Consecutive::batch_mint(&e, &user, 5), 4);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn consecutive_batch_mint_above_max_batch_size_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::set_max_batch_size(&e, 5);
+        Consecutive::batch_mint(&e, &user, 6);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn consecutive_batch_mint_zero_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &user, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn consecutive_set_max_batch_size_zero_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Consecutive::set_max_batch_size(&e, 0);
+    });
+}
+
+#[test]
+fn consecutive_owner_of_reads_are_bounded() {
+    let e = Env::default();
+    let address = e.register(ConsecutiveContract, ());
+    let client = ConsecutiveContractClient::new(&e, &address);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let last_id = DEFAULT_MAX_BATCH_SIZE - 1;
+
+    // the last token of a batch of max. size, within the default budget
+    client.batch_mint(&user1, &DEFAULT_MAX_BATCH_SIZE);
+    e.cost_estimate().budget().reset_default();
+    assert_eq!(client.owner_of(&last_id), user1);
+    let single_batch_reads = e.cost_estimate().resources().read_entries;
+
+    // 256 batches in total take at most 8 more checkpoint reads
+    e.cost_estimate().budget().reset_unlimited();
+    for _ in 0..255 {
+        client.batch_mint(&user2, &1);
+    }
+    e.cost_estimate().budget().reset_default();
+    assert_eq!(client.owner_of(&last_id), user1);
+    let many_batches_reads = e.cost_estimate().resources().read_entries;
+
+    assert!(many_batches_reads <= single_batch_reads + 8);
+}
+
+#[test]
+fn consecutive_owner_of_legacy_tokens_reads_are_bounded() {
+    let e = Env::default();
+    let address = e.register(ConsecutiveContract, ());
+    let client = ConsecutiveContractClient::new(&e, &address);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let last_legacy_id = DEFAULT_MAX_BATCH_SIZE - 1;
+
+    client.legacy_batch_mint(&user1, &DEFAULT_MAX_BATCH_SIZE);
+
+    // not migrated yet: fails without walking back the preceding tokens
+    e.cost_estimate().budget().reset_default();
+    assert_eq!(
+        client.try_owner_of(&last_legacy_id),
+        Err(Ok(NonFungibleTokenError::NonExistentToken.into()))
+    );
+
+    client.migrate_batch_checkpoints(&vec![
+        &e,
+        BatchCheckpoint { first_token_id: 0, owner: user1.clone() },
+    ]);
+    client.batch_mint(&user2, &DEFAULT_MAX_BATCH_SIZE);
+
+    // a migrated batch is resolved like a batch minted with a checkpoint
+    e.cost_estimate().budget().reset_default();
+    assert_eq!(client.owner_of(&last_legacy_id), user1);
+    let legacy_reads = e.cost_estimate().resources().read_entries;
+
+    e.cost_estimate().budget().reset_default();
+    assert_eq!(client.owner_of(&(2 * DEFAULT_MAX_BATCH_SIZE - 1)), user2);
+    let batch_reads = e.cost_estimate().resources().read_entries;
+
+    // both binary search the 2 checkpoints, i.e. take at most 1 more read,
+    // instead of walking back the batch
+    assert!(legacy_reads <= batch_reads + 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn consecutive_owner_of_legacy_tokens_without_migration_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        legacy_batch_mint(&e, &user1, 5); // 0..=4
+        Consecutive::batch_mint(&e, &user2, 3); // 5..=7
+
+        assert_eq!(Consecutive::owner_of(&e, 0), user1);
+        assert_eq!(Consecutive::owner_of(&e, 5), user2);
+        Consecutive::owner_of(&e, 4);
+    });
+}
+
+#[test]
+fn consecutive_migrate_batch_checkpoints_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        legacy_batch_mint(&e, &user1, 5); // 0..=4
+        legacy_batch_mint(&e, &user2, 2); // 5..=6
+
+        // migrated in two chunks
+        let checkpoint1 = BatchCheckpoint { first_token_id: 0, owner: user1.clone() };
+        let checkpoint2 = BatchCheckpoint { first_token_id: 5, owner: user2.clone() };
+        Consecutive::migrate_batch_checkpoints(&e, &vec![&e, checkpoint1]);
+        Consecutive::migrate_batch_checkpoints(&e, &vec![&e, checkpoint2]);
+
+        Consecutive::batch_mint(&e, &user3, 3); // 7..=9
+        assert_eq!(Consecutive::batch_of(&e, 6).unwrap().owner, user2);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &user1, &user3, 2);
+
+        assert_eq!(Consecutive::owner_of(&e, 1), user1);
+        assert_eq!(Consecutive::owner_of(&e, 2), user3);
+        assert_eq!(Consecutive::owner_of(&e, 3), user1);
+        assert_eq!(Consecutive::owner_of(&e, 4), user1);
+        assert_eq!(Consecutive::owner_of(&e, 6), user2);
+        assert_eq!(Consecutive::owner_of(&e, 9), user3);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #330)")]
+fn consecutive_migrate_batch_checkpoints_after_new_batch_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        legacy_batch_mint(&e, &user1, 5);
+        Consecutive::batch_mint(&e, &user2, 3);
+
+        let checkpoint = BatchCheckpoint { first_token_id: 0, owner: user1.clone() };
+        Consecutive::migrate_batch_checkpoints(&e, &vec![&e, checkpoint]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #330)")]
+fn consecutive_migrate_batch_checkpoints_of_unminted_tokens_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        legacy_batch_mint(&e, &user1, 5);
+
+        let checkpoint = BatchCheckpoint { first_token_id: 5, owner: user1.clone() };
+        Consecutive::migrate_batch_checkpoints(&e, &vec![&e, checkpoint]);
+    });
+}
+
+#[test]
+fn consecutive_batch_mint_to_many_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (user1.clone(), 3), (user2.clone(), 2), (user1.clone(), 1)];
+        let last_id = Consecutive::batch_mint_to_many(&e, &recipients);
+
+        assert_eq!(last_id, 5);
+        assert_eq!(next_token_id(&e), 6);
+        assert_eq!(Base::balance(&e, &user1), 4);
+        assert_eq!(Base::balance(&e, &user2), 2);
+        assert_eq!(Consecutive::owner_of(&e, 2), user1);
+        assert_eq!(Consecutive::owner_of(&e, 3), user2);
+        assert_eq!(Consecutive::owner_of(&e, 4), user2);
+        assert_eq!(Consecutive::owner_of(&e, 5), user1);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_consecutive_mints(&[(&user1, 0, 2), (&user2, 3, 4), (&user1, 5, 5)]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn consecutive_batch_mint_to_many_with_no_recipients_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint_to_many(&e, &vec![&e]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn consecutive_batch_mint_to_many_with_too_many_recipients_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let mut recipients = Vec::new(&e);
+        for _ in 0..=MAX_BATCH_MINT_RECIPIENTS {
+            recipients.push_back((user.clone(), 1));
+        }
+        Consecutive::batch_mint_to_many(&e, &recipients);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn consecutive_batch_mint_to_many_with_zero_amount_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint_to_many(&e, &vec![&e, (user1, 3), (user2, 0)]);
+    });
+}
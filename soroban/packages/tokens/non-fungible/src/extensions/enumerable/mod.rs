@@ -2,11 +2,16 @@ pub mod storage;
 
 mod test;
 
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 pub use storage::Enumerable;
 
 use crate::{Balance, NonFungibleToken, TokenId};
 
+/// Max. number of `token_id`s returned by a single page of
+/// [`NonFungibleEnumerable::tokens_of_owner()`] and
+/// [`NonFungibleEnumerable::all_tokens()`].
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
 /// Enumerable Trait for Non-Fungible Token
 ///
 /// The `NonFungibleEnumerable` trait extends the `NonFungibleToken` trait to
@@ -37,8 +42,11 @@ use crate::{Balance, NonFungibleToken, TokenId};
 /// # Notes
 ///
 /// Enumerable trait has its own business logic for creating and destroying
-/// tokens. Therefore, this trait is INCOMPATIBLE with the `Mintable`,
-/// `Burnable`, and `Consecutive` extensions.
+/// tokens. Therefore, this trait is INCOMPATIBLE with the `Mintable` and
+/// `Consecutive` extensions (see [`crate::enumerable_consecutive`] for
+/// enumerable collections minted in batches). `Burnable` burns the tokens with
+/// [`Enumerable::burn`] and [`Enumerable::burn_from`], which keep the
+/// enumerations up to date.
 ///
 /// Note that, `Enumerable` trait can also be offloaded to off-chain services.
 /// This extension exists for the use-cases where the enumeration is required as
@@ -96,4 +104,33 @@ pub trait NonFungibleEnumerable: NonFungibleToken<ContractType = Enumerable> {
     /// `token_id`s. For sequential `token_id`s, no need to call a function,
     /// the `token_id` itself acts as the global index.
     fn get_token_id(e: &Env, index: TokenId) -> TokenId;
+
+    /// Returns up to `limit` `token_id`s owned by `owner`, starting at
+    /// `start` in the owner's local list. `limit` is capped at
+    /// [`MAX_PAGE_LIMIT`], and the returned list is shorter than `limit`
+    /// once the end of the list is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    /// * `start` - Index of the first token in the owner's local list.
+    /// * `limit` - Max. number of `token_id`s to return.
+    fn tokens_of_owner(e: &Env, owner: Address, start: TokenId, limit: u32) -> Vec<TokenId>;
+
+    /// Returns up to `limit` `token_id`s, starting at `start` in the global
+    /// token list. `limit` is capped at [`MAX_PAGE_LIMIT`], and the returned
+    /// list is shorter than `limit` once the end of the list is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `start` - Index of the first token in the global list.
+    /// * `limit` - Max. number of `token_id`s to return.
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function is only intended for non-sequential
+    /// `token_id`s, see [`NonFungibleEnumerable::get_token_id()`].
+    fn all_tokens(e: &Env, start: TokenId, limit: u32) -> Vec<TokenId>;
 }
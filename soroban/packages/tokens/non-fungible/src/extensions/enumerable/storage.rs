@@ -1,10 +1,12 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
 use stellar_constants::{
     OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD,
 };
 
 use crate::{
-    non_fungible::emit_mint, Balance, Base, ContractOverrides, NonFungibleTokenError, TokenId,
+    capped::record_mint, enumerable::MAX_PAGE_LIMIT, non_fungible::emit_mint,
+    random_ids::next_random_token_id, sequential, Balance, Base, ContractOverrides,
+    NonFungibleTokenError, TokenId,
 };
 
 pub struct Enumerable;
@@ -17,6 +19,28 @@ impl ContractOverrides for Enumerable {
     fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: TokenId) {
         Enumerable::transfer_from(e, spender, from, to, token_id);
     }
+
+    fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        Enumerable::batch_transfer(e, from, to, token_ids);
+    }
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        Enumerable::batch_transfer_from(e, spender, from, to, token_ids);
+    }
+
+    fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Enumerable::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Enumerable::burn_from(e, spender, from, token_id);
+    }
 }
 
 #[contracttype]
@@ -102,6 +126,59 @@ impl Enumerable {
         token_id
     }
 
+    /// Returns up to `limit` `token_id`s owned by `owner`, starting at
+    /// `start` in the owner's local list. `limit` is capped at
+    /// [`MAX_PAGE_LIMIT`], and the returned list is shorter than `limit`
+    /// once the end of the list is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    /// * `start` - Index of the first token in the owner's local list.
+    /// * `limit` - Max. number of `token_id`s to return.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Enumerable::get_owner_token_id`] errors.
+    pub fn tokens_of_owner(e: &Env, owner: &Address, start: TokenId, limit: u32) -> Vec<TokenId> {
+        let end = Enumerable::page_end(start, limit, Base::balance(e, owner));
+
+        let mut token_ids = Vec::new(e);
+        for index in start..end {
+            token_ids.push_back(Enumerable::get_owner_token_id(e, owner, index));
+        }
+        token_ids
+    }
+
+    /// Returns up to `limit` `token_id`s, starting at `start` in the global
+    /// token list. `limit` is capped at [`MAX_PAGE_LIMIT`], and the returned
+    /// list is shorter than `limit` once the end of the list is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `start` - Index of the first token in the global list.
+    /// * `limit` - Max. number of `token_id`s to return.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Enumerable::get_token_id`] errors.
+    ///
+    /// # Notes
+    ///
+    /// **IMPORTANT**: This function is only intended for non-sequential
+    /// `token_id`s, see [`Enumerable::get_token_id`].
+    pub fn all_tokens(e: &Env, start: TokenId, limit: u32) -> Vec<TokenId> {
+        let end = Enumerable::page_end(start, limit, Enumerable::total_supply(e));
+
+        let mut token_ids = Vec::new(e);
+        for index in start..end {
+            token_ids.push_back(Enumerable::get_token_id(e, index));
+        }
+        token_ids
+    }
+
     // ################## CHANGE STATE ##################
 
     /// Creates a token with the next available `token_id` and assigns it to
@@ -141,6 +218,42 @@ impl Enumerable {
         token_id
     }
 
+    /// Creates a token with the next available `token_id` for each of the
+    /// `recipients`, in the given order. Returns the first and the last
+    /// minted `token_id`s.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recipients` - The addresses receiving the new tokens, one token per
+    ///   entry.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_batch_mint_recipients`] errors.
+    /// * refer to [`Enumerable::sequential_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// This is a wrapper around [`Enumerable::sequential_mint()`], see its
+    /// notes.
+    pub fn batch_mint_to(e: &Env, recipients: &Vec<Address>) -> (TokenId, TokenId) {
+        Base::check_batch_mint_recipients(e, recipients);
+
+        let first_id = sequential::next_token_id(e);
+        let mut last_id = first_id;
+        for to in recipients.iter() {
+            last_id = Enumerable::sequential_mint(e, &to);
+        }
+        (first_id, last_id)
+    }
+
     /// Creates a token with the provided `token_id` and assigns it to `to`.
     ///
     /// # Arguments
@@ -151,6 +264,7 @@ impl Enumerable {
     ///
     /// # Errors
     ///
+    /// * refer to [`record_mint`] errors.
     /// * refer to [`Base::update`] errors.
     /// * refer to [`increment_total_supply`] errors.
     ///
@@ -165,6 +279,7 @@ impl Enumerable {
     /// * owner_tokens enumeration
     /// * global_tokens enumeration
     pub fn non_sequential_mint(e: &Env, to: &Address, token_id: TokenId) {
+        record_mint(e, 1);
         Base::update(e, None, Some(to), token_id);
         emit_mint(e, to, token_id);
 
@@ -175,6 +290,36 @@ impl Enumerable {
         Enumerable::add_to_global_enumeration(e, token_id, total_supply);
     }
 
+    /// Creates a token with a random `token_id` drawn from the range set with
+    /// [`crate::random_ids::set_token_id_range`], and assigns it to `to`.
+    /// Returns the `token_id` for the newly minted token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`next_random_token_id`] errors.
+    /// * refer to [`Enumerable::non_sequential_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// This is a wrapper around [`Enumerable::non_sequential_mint()`], as
+    /// random `token_id`s need the global enumeration.
+    pub fn random_mint(e: &Env, to: &Address) -> TokenId {
+        let token_id = next_random_token_id(e);
+        Enumerable::non_sequential_mint(e, to, token_id);
+
+        token_id
+    }
+
     /// Destroys the `token_id` from `account`.
     ///
     /// # Arguments
@@ -323,6 +468,63 @@ impl Enumerable {
         Enumerable::remove_from_global_enumeration(e, token_id, total_supply);
     }
 
+    /// Destroys the `token_id` from `account`, with
+    /// [`Enumerable::non_sequential_burn`] if the token is part of the global
+    /// token list, and with [`Enumerable::sequential_burn`] otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Enumerable::sequential_burn`] errors.
+    /// * refer to [`Enumerable::non_sequential_burn`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    pub fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        if Enumerable::is_globally_enumerated(e, token_id) {
+            Enumerable::non_sequential_burn(e, from, token_id);
+        } else {
+            Enumerable::sequential_burn(e, from, token_id);
+        }
+    }
+
+    /// Destroys the `token_id` from `account`, by using `spender`s approval,
+    /// with [`Enumerable::non_sequential_burn_from`] if the token is part of
+    /// the global token list, and with [`Enumerable::sequential_burn_from`]
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The account that is allowed to burn the token on behalf of
+    ///   the owner.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Enumerable::sequential_burn_from`] errors.
+    /// * refer to [`Enumerable::non_sequential_burn_from`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        if Enumerable::is_globally_enumerated(e, token_id) {
+            Enumerable::non_sequential_burn_from(e, spender, from, token_id);
+        } else {
+            Enumerable::sequential_burn_from(e, spender, from, token_id);
+        }
+    }
+
     /// Transfers a non-fungible token (NFT), ensuring ownership checks.
     ///
     /// # Arguments
@@ -569,4 +771,17 @@ impl Enumerable {
         e.storage().persistent().remove(&StorageKey::GlobalTokens(last_token_index));
         e.storage().persistent().remove(&StorageKey::GlobalTokensIndex(to_be_removed_id));
     }
+
+    /// Returns whether `token_id` is part of the global token list, i.e.
+    /// whether it was minted with a non-sequential strategy.
+    fn is_globally_enumerated(e: &Env, token_id: TokenId) -> bool {
+        e.storage().persistent().has(&StorageKey::GlobalTokensIndex(token_id))
+    }
+
+    /// Returns the index right after the last one of a page starting at
+    /// `start`, in a list of `len` elements.
+    fn page_end(start: TokenId, limit: u32, len: Balance) -> TokenId {
+        let limit = limit.min(MAX_PAGE_LIMIT) as TokenId;
+        start.saturating_add(limit).min(len)
+    }
 }
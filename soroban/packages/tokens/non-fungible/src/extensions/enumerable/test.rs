@@ -2,10 +2,13 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env};
 use stellar_event_assertion::EventAssertion;
 
-use crate::{extensions::enumerable::Enumerable, Base, StorageKey, TokenId};
+use crate::{
+    extensions::enumerable::{Enumerable, MAX_PAGE_LIMIT},
+    Base, StorageKey, TokenId,
+};
 
 #[contract]
 struct MockContract;
@@ -64,6 +67,74 @@ fn test_get_token_id() {
     });
 }
 
+#[test]
+fn test_tokens_of_owner() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        for _ in 0..3 {
+            Enumerable::sequential_mint(&e, &owner);
+            Enumerable::sequential_mint(&e, &other);
+        }
+
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner, 0, 10), vec![&e, 0, 2, 4]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner, 1, 1), vec![&e, 2]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &other, 1, 10), vec![&e, 3, 5]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner, 3, 10), vec![&e]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner, 0, 0), vec![&e]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner, TokenId::MAX, u32::MAX), vec![&e]);
+    });
+}
+
+#[test]
+fn test_all_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Enumerable::non_sequential_mint(&e, &owner, 42);
+        Enumerable::non_sequential_mint(&e, &owner, 83);
+        Enumerable::non_sequential_mint(&e, &owner, 7);
+
+        assert_eq!(Enumerable::all_tokens(&e, 0, 10), vec![&e, 42, 83, 7]);
+        assert_eq!(Enumerable::all_tokens(&e, 1, 1), vec![&e, 83]);
+        assert_eq!(Enumerable::all_tokens(&e, 3, 10), vec![&e]);
+
+        Enumerable::non_sequential_burn(&e, &owner, 42);
+        assert_eq!(Enumerable::all_tokens(&e, 0, 10), vec![&e, 7, 83]);
+    });
+}
+
+#[test]
+fn test_pages_are_capped() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.cost_estimate().budget().reset_unlimited();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let minted = MAX_PAGE_LIMIT + 5;
+
+    e.as_contract(&address, || {
+        for token_id in 0..minted {
+            Enumerable::non_sequential_mint(&e, &owner, token_id as TokenId);
+        }
+
+        let page = Enumerable::tokens_of_owner(&e, &owner, 0, u32::MAX);
+        assert_eq!(page.len(), MAX_PAGE_LIMIT);
+        assert_eq!(page.last(), Some((MAX_PAGE_LIMIT - 1) as TokenId));
+
+        let page = Enumerable::all_tokens(&e, MAX_PAGE_LIMIT as TokenId, u32::MAX);
+        assert_eq!(page.len(), 5);
+        assert_eq!(page.first(), Some(MAX_PAGE_LIMIT as TokenId));
+    });
+}
+
 #[test]
 fn test_sequential_mint() {
     let e = Env::default();
@@ -284,3 +355,37 @@ fn test_enumerable_transfer_from() {
         assert_eq!(Enumerable::total_supply(&e), 1);
     });
 }
+
+#[test]
+fn batch_mint_to_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner1 = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, owner1.clone(), owner2.clone(), owner1.clone()];
+        assert_eq!(Enumerable::batch_mint_to(&e, &recipients), (0, 2));
+
+        assert_eq!(Enumerable::total_supply(&e), 3);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner1, 0, 10), vec![&e, 0, 2]);
+        assert_eq!(Enumerable::tokens_of_owner(&e, &owner2, 0, 10), vec![&e, 1]);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_non_fungible_mint(&owner1, 0);
+        event_assert.assert_non_fungible_mint(&owner2, 1);
+        event_assert.assert_non_fungible_mint(&owner1, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn batch_mint_to_no_recipients_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Enumerable::batch_mint_to(&e, &vec![&e]);
+    });
+}
@@ -0,0 +1,100 @@
+//! # Enumerable Consecutive Extension for Non-Fungible Token
+//!
+//! The `enumerable_consecutive` module provides a contract type combining the
+//! cheap batch minting of [`crate::consecutive`] with the on-chain enumeration
+//! of [`crate::enumerable`], which are otherwise incompatible.
+//!
+//! ## Implementation Notes
+//!
+//! - **Ownership**: Ownership is tracked exactly as in
+//!   [`crate::consecutive::Consecutive`], i.e. owners are stored only at
+//!   boundaries and inferred for the other tokens.
+//! - **Minting**: `batch_mint` records the batch once in the global list and
+//!   once in the recipient's list, instead of storing every token ID. The
+//!   position of a token in a list is inferred from the batch it belongs to.
+//! - **Transfer & Burn**: The token is removed from the owner's list (and from
+//!   the global list on burn) by moving the last token of the list in its
+//!   place, as in [`crate::enumerable::Enumerable`]. Only the moved tokens get
+//!   their position stored explicitly.
+//!
+//! ## Usage
+//!
+//! - **IMPORTANT** - For minting tokens ONLY the functions
+//!   [`EnumerableConsecutive::batch_mint`] and
+//!   [`EnumerableConsecutive::batch_mint_to_many`] must be used. Using other
+//!   minting functions will break the logic of tracking ownership and
+//!   enumeration.
+//! - The order of the tokens in the lists is not preserved on removals.
+pub mod storage;
+pub use storage::EnumerableConsecutive;
+
+mod test;
+
+use soroban_sdk::{Address, Env};
+
+use crate::{Balance, NonFungibleToken, TokenId};
+
+/// Enumerable Consecutive Trait for Non-Fungible Token
+///
+/// The `NonFungibleEnumerableConsecutive` trait extends the `NonFungibleToken`
+/// trait to enumerate the tokens of an account and all the tokens of a
+/// contract, which mints its tokens in consecutive batches.
+///
+/// Every owner's list starts with the local index `0`, and the last token of
+/// the owner can be found with `balance() - 1`. Likewise, the global list
+/// starts with the index `0`, and the last token can be found with
+/// `total_supply() - 1`.
+///
+/// # Notes
+///
+/// This trait is INCOMPATIBLE with the `Enumerable` and `Consecutive`
+/// extensions, whose logic it combines.
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`NonFungibleEnumerableConsecutive`] trait for your Smart
+/// Contract, you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl NonFungibleEnumerableConsecutive for MyContract {
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait NonFungibleEnumerableConsecutive:
+    NonFungibleToken<ContractType = EnumerableConsecutive>
+{
+    /// Returns the total amount of tokens stored by the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    fn total_supply(e: &Env) -> Balance;
+
+    /// Returns the `token_id` owned by `owner` at a given `index` in the
+    /// owner's local list. Use along with
+    /// [`crate::NonFungibleToken::balance()`] to enumerate all of `owner`'s
+    /// tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    /// * `index` - Index of the token in the owner's local list.
+    fn get_owner_token_id(e: &Env, owner: Address, index: TokenId) -> TokenId;
+
+    /// Returns the `token_id` at a given `index` in the global token list.
+    /// Use along with [`NonFungibleEnumerableConsecutive::total_supply()`] to
+    /// enumerate all the tokens in the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `index` - Index of the token in the global list.
+    fn get_token_id(e: &Env, index: TokenId) -> TokenId;
+}
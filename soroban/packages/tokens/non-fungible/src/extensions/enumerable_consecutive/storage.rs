@@ -0,0 +1,585 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String, TryFromVal, Val, Vec};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{
+    consecutive::Consecutive, enumerable::storage::OwnerTokensKey, ApprovalData, Balance, Base,
+    ContractOverrides, NonFungibleTokenError, TokenId, MAX_BATCH_MINT_RECIPIENTS,
+};
+
+pub struct EnumerableConsecutive;
+
+impl ContractOverrides for EnumerableConsecutive {
+    fn owner_of(e: &Env, token_id: TokenId) -> Address {
+        Consecutive::owner_of(e, token_id)
+    }
+
+    fn token_uri(e: &Env, token_id: TokenId) -> String {
+        Consecutive::token_uri(e, token_id)
+    }
+
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        EnumerableConsecutive::transfer(e, from, to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: TokenId) {
+        EnumerableConsecutive::transfer_from(e, spender, from, to, token_id);
+    }
+
+    fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        EnumerableConsecutive::batch_transfer(e, from, to, token_ids);
+    }
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        EnumerableConsecutive::batch_transfer_from(e, spender, from, to, token_ids);
+    }
+
+    fn batch_approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_ids: &Vec<TokenId>,
+        live_until_ledger: u32,
+    ) {
+        Consecutive::batch_approve(e, approver, approved, token_ids, live_until_ledger);
+    }
+
+    fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        EnumerableConsecutive::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        EnumerableConsecutive::burn_from(e, spender, from, token_id);
+    }
+
+    fn approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_id: TokenId,
+        live_until_ledger: u32,
+    ) {
+        Consecutive::approve(e, approver, approved, token_id, live_until_ledger);
+    }
+
+    fn approval_of(e: &Env, token_id: TokenId) -> Option<ApprovalData> {
+        Consecutive::approval_of(e, token_id)
+    }
+
+    fn revoke_approval(e: &Env, approver: &Address, token_id: TokenId) {
+        Consecutive::revoke_approval(e, approver, token_id);
+    }
+
+    fn set_user(e: &Env, caller: &Address, token_id: TokenId, user: &Address, expires_ledger: u32) {
+        Consecutive::set_user(e, caller, token_id, user, expires_ledger);
+    }
+}
+
+/// A batch of consecutive `token_id`s, occupying consecutive indices of a
+/// token list from `first_index` onwards.
+#[contracttype]
+pub struct Batch {
+    pub first_index: TokenId,
+    pub first_token_id: TokenId,
+}
+
+#[contracttype]
+pub struct OwnerBatchKey {
+    pub owner: Address,
+    pub index: u32,
+}
+
+/// Storage keys for the data associated with `EnumerableConsecutive`
+#[contracttype]
+pub enum StorageKey {
+    TotalSupply,
+    GlobalBatchCount,
+    GlobalBatch(/* index */ u32),
+    GlobalTokens(/* index */ TokenId),
+    GlobalTokensIndex(/* token_id */ TokenId),
+    OwnerBatchCount(Address),
+    OwnerBatch(OwnerBatchKey),
+    OwnerTokens(OwnerTokensKey),
+    OwnerTokensIndex(/* token_id */ TokenId),
+}
+
+impl EnumerableConsecutive {
+    // ################## QUERY STATE ##################
+
+    /// Returns the total amount of tokens stored by the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn total_supply(e: &Env) -> Balance {
+        e.storage().instance().get(&StorageKey::TotalSupply).unwrap_or(0)
+    }
+
+    /// Returns the `token_id` owned by `owner` at a given `index` in the
+    /// owner's local list.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - Account of the token's owner.
+    /// * `index` - Index of the token in the owner's local list.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::TokenNotFoundInOwnerList`] - When `index` is
+    ///   out of the owner's list.
+    pub fn get_owner_token_id(e: &Env, owner: &Address, index: TokenId) -> TokenId {
+        let list = TokenList::Owner(owner);
+        if index >= list.len(e) {
+            panic_with_error!(e, list.not_found());
+        }
+        list.token_at(e, index)
+    }
+
+    /// Returns the `token_id` at a given `index` in the global token list.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `index` - Index of the token in the global list.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::TokenNotFoundInGlobalList`] - When `index`
+    ///   is out of the global list.
+    pub fn get_token_id(e: &Env, index: TokenId) -> TokenId {
+        let list = TokenList::Global;
+        if index >= list.len(e) {
+            panic_with_error!(e, list.not_found());
+        }
+        list.token_at(e, index)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Mints a batch of tokens with consecutive ids and attributes them to
+    /// `to`, recording the batch in the global list and in the list of `to`.
+    /// Returns the last minted `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address of the recipient.
+    /// * `amount` - The number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::MathOverflow`] - When the total supply
+    ///   overflows.
+    /// * refer to [`Consecutive::batch_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["consecutive_mint", to: Address]`
+    /// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The function intentionally lacks authorization controls.
+    /// You MUST invoke it only from the constructor or implement proper
+    /// authorization in the calling function.
+    pub fn batch_mint(e: &Env, to: &Address, amount: Balance) -> TokenId {
+        let total_supply = EnumerableConsecutive::total_supply(e);
+        let balance = Base::balance(e, to);
+
+        let last_id = Consecutive::batch_mint(e, to, amount);
+        let first_token_id = last_id + 1 - amount;
+
+        let Some(new_total_supply) = total_supply.checked_add(amount) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+        e.storage().instance().set(&StorageKey::TotalSupply, &new_total_supply);
+
+        TokenList::Global.push_batch(e, Batch { first_index: total_supply, first_token_id });
+        TokenList::Owner(to).push_batch(e, Batch { first_index: balance, first_token_id });
+
+        last_id
+    }
+
+    /// Mints consecutive batches of tokens to multiple recipients, one batch
+    /// per `(recipient, amount)` pair, in the given order, and records them
+    /// as [`EnumerableConsecutive::batch_mint`] does. Returns the last minted
+    /// `token_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recipients` - The recipients along with their number of tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidBatchSize`] - If `recipients` is
+    ///   empty or has more than [`MAX_BATCH_MINT_RECIPIENTS`] entries.
+    /// * refer to [`EnumerableConsecutive::batch_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    /// * topics - `["consecutive_mint", to: Address]`
+    /// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: The function intentionally lacks authorization controls.
+    /// You MUST invoke it only from the constructor or implement proper
+    /// authorization in the calling function.
+    pub fn batch_mint_to_many(e: &Env, recipients: &Vec<(Address, Balance)>) -> TokenId {
+        if recipients.is_empty() || recipients.len() > MAX_BATCH_MINT_RECIPIENTS {
+            panic_with_error!(e, NonFungibleTokenError::InvalidBatchSize);
+        }
+
+        let mut last_id = 0;
+        for (to, amount) in recipients.iter() {
+            last_id = EnumerableConsecutive::batch_mint(e, &to, amount);
+        }
+        last_id
+    }
+
+    /// Transfers a non-fungible token (NFT), ensuring ownership checks, and
+    /// moves it from the list of `from` to the list of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment reference.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::transfer`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Consecutive::transfer(e, from, to, token_id);
+
+        TokenList::Owner(from).remove(e, token_id);
+        TokenList::Owner(to).push(e, token_id);
+    }
+
+    /// Transfers a non-fungible token (NFT), ensuring ownership and approval
+    /// checks, and moves it from the list of `from` to the list of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment reference.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::transfer_from`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+    ) {
+        Consecutive::transfer_from(e, spender, from, to, token_id);
+
+        TokenList::Owner(from).remove(e, token_id);
+        TokenList::Owner(to).push(e, token_id);
+    }
+
+    /// Destroys the `token_id` from `account`, ensuring ownership checks, and
+    /// removes it from the global list and from the list of `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::burn`] errors.
+    /// * refer to [`decrement_total_supply`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `from` is required.
+    pub fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Consecutive::burn(e, from, token_id);
+
+        EnumerableConsecutive::remove_burned(e, from, token_id);
+    }
+
+    /// Destroys the `token_id` from `account`, by using `spender`s approval,
+    /// and removes it from the global list and from the list of `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The account that is allowed to burn the token on behalf of
+    ///   the owner.
+    /// * `from` - The account whose token is destroyed.
+    /// * `token_id` - The token to burn.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::burn_from`] errors.
+    /// * refer to [`decrement_total_supply`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["burn", from: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// Authorization for `spender` is required.
+    pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Consecutive::burn_from(e, spender, from, token_id);
+
+        EnumerableConsecutive::remove_burned(e, from, token_id);
+    }
+
+    /// Low-level function for transferring an NFT, without handling
+    /// authorization. Updates the ownership records as
+    /// [`Consecutive::update`] does, and moves the token from the list of
+    /// `from` to the list of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::update`] errors.
+    pub fn move_token(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Consecutive::update(e, Some(from), Some(to), token_id);
+
+        TokenList::Owner(from).remove(e, token_id);
+        TokenList::Owner(to).push(e, token_id);
+    }
+
+    /// Decrements the total supply by 1 and returns the new total supply.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::MathOverflow`] - When the total supply is
+    ///   already `0`.
+    pub fn decrement_total_supply(e: &Env) -> Balance {
+        let Some(total_supply) = EnumerableConsecutive::total_supply(e).checked_sub(1) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+        e.storage().instance().set(&StorageKey::TotalSupply, &total_supply);
+        total_supply
+    }
+
+    // ################## INTERNAL HELPERS ##################
+
+    /// Removes a burned token from the list of `from` and from the global
+    /// list. Must be called after the balance of `from` is decreased.
+    fn remove_burned(e: &Env, from: &Address, token_id: TokenId) {
+        TokenList::Owner(from).remove(e, token_id);
+
+        EnumerableConsecutive::decrement_total_supply(e);
+        TokenList::Global.remove(e, token_id);
+    }
+}
+
+/// A list of tokens, either the global list or the local list of an owner.
+///
+/// The tokens of a list are not stored one by one: each minted batch is
+/// recorded with the index of its first token, and the position of a token in
+/// the list is inferred from the latest batch starting at or before it. Only
+/// the tokens moved by a removal (or added by a transfer) have their index
+/// stored, and these stored indices take precedence over the batches.
+///
+/// A batch starting at or after the end of the list is obsolete: all of its
+/// tokens were removed or moved to lower indices. Such batches are discarded
+/// when a new batch is pushed, so the batches are always sorted by both
+/// `first_index` and `first_token_id`.
+enum TokenList<'a> {
+    Global,
+    Owner(&'a Address),
+}
+
+impl TokenList<'_> {
+    fn len(&self, e: &Env) -> Balance {
+        match self {
+            TokenList::Global => EnumerableConsecutive::total_supply(e),
+            TokenList::Owner(owner) => Base::balance(e, owner),
+        }
+    }
+
+    fn not_found(&self) -> NonFungibleTokenError {
+        match self {
+            TokenList::Global => NonFungibleTokenError::TokenNotFoundInGlobalList,
+            TokenList::Owner(_) => NonFungibleTokenError::TokenNotFoundInOwnerList,
+        }
+    }
+
+    fn batch_count_key(&self) -> StorageKey {
+        match self {
+            TokenList::Global => StorageKey::GlobalBatchCount,
+            TokenList::Owner(owner) => StorageKey::OwnerBatchCount((*owner).clone()),
+        }
+    }
+
+    fn batch_key(&self, index: u32) -> StorageKey {
+        match self {
+            TokenList::Global => StorageKey::GlobalBatch(index),
+            TokenList::Owner(owner) => {
+                StorageKey::OwnerBatch(OwnerBatchKey { owner: (*owner).clone(), index })
+            }
+        }
+    }
+
+    fn token_key(&self, index: TokenId) -> StorageKey {
+        match self {
+            TokenList::Global => StorageKey::GlobalTokens(index),
+            TokenList::Owner(owner) => {
+                StorageKey::OwnerTokens(OwnerTokensKey { owner: (*owner).clone(), index })
+            }
+        }
+    }
+
+    fn index_key(&self, token_id: TokenId) -> StorageKey {
+        match self {
+            TokenList::Global => StorageKey::GlobalTokensIndex(token_id),
+            TokenList::Owner(_) => StorageKey::OwnerTokensIndex(token_id),
+        }
+    }
+
+    fn batch_count(&self, e: &Env) -> u32 {
+        read(e, &self.batch_count_key()).unwrap_or(0)
+    }
+
+    fn batch(&self, e: &Env, index: u32) -> Batch {
+        read(e, &self.batch_key(index)).unwrap_or_else(|| panic_with_error!(e, self.not_found()))
+    }
+
+    /// Records a new batch, discarding the obsolete ones. The new batch is
+    /// stored in place of the first obsolete batch, if any, instead of
+    /// removing and re-creating the entry.
+    fn push_batch(&self, e: &Env, batch: Batch) {
+        let old_count = self.batch_count(e);
+        let mut count = old_count;
+        while count > 0 && self.batch(e, count - 1).first_index >= batch.first_index {
+            count -= 1;
+        }
+        for index in (count + 1)..old_count {
+            e.storage().persistent().remove(&self.batch_key(index));
+        }
+        e.storage().persistent().set(&self.batch_key(count), &batch);
+        e.storage().persistent().set(&self.batch_count_key(), &(count + 1));
+    }
+
+    /// Returns the latest batch whose `first_index` (or `first_token_id` if
+    /// `by_token_id`) is lower than or equal to `value`.
+    fn find_batch(&self, e: &Env, value: TokenId, by_token_id: bool) -> Batch {
+        let key =
+            |batch: &Batch| if by_token_id { batch.first_token_id } else { batch.first_index };
+
+        // batches before `low` start at or before `value`, batches from `high`
+        // start after `value`
+        let (mut low, mut high) = (0u32, self.batch_count(e));
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if key(&self.batch(e, mid)) <= value {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            panic_with_error!(e, self.not_found());
+        }
+        self.batch(e, low - 1)
+    }
+
+    /// Returns the `token_id` at `index`, which must be in the list.
+    fn token_at(&self, e: &Env, index: TokenId) -> TokenId {
+        read(e, &self.token_key(index)).unwrap_or_else(|| {
+            let batch = self.find_batch(e, index, false);
+            batch.first_token_id + (index - batch.first_index)
+        })
+    }
+
+    /// Returns the index of `token_id`, which must be in the list.
+    fn index_of(&self, e: &Env, token_id: TokenId) -> TokenId {
+        read(e, &self.index_key(token_id)).unwrap_or_else(|| {
+            let batch = self.find_batch(e, token_id, true);
+            batch.first_index + (token_id - batch.first_token_id)
+        })
+    }
+
+    /// Appends `token_id` to the list. Must be called after the length of the
+    /// list is increased.
+    fn push(&self, e: &Env, token_id: TokenId) {
+        let Some(index) = self.len(e).checked_sub(1) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+        e.storage().persistent().set(&self.token_key(index), &token_id);
+        e.storage().persistent().set(&self.index_key(token_id), &index);
+    }
+
+    /// Removes `token_id` from the list by moving the last token in its place.
+    /// Must be called after the length of the list is decreased.
+    fn remove(&self, e: &Env, token_id: TokenId) {
+        let index = self.index_of(e, token_id);
+        let last_index = self.len(e);
+
+        if index != last_index {
+            let last_token_id = self.token_at(e, last_index);
+            e.storage().persistent().set(&self.token_key(index), &last_token_id);
+            e.storage().persistent().set(&self.index_key(last_token_id), &index);
+        }
+
+        e.storage().persistent().remove(&self.token_key(last_index));
+        e.storage().persistent().remove(&self.index_key(token_id));
+    }
+}
+
+/// Reads `key` from the persistent storage and extends its TTL if it exists.
+fn read<V: TryFromVal<Env, Val>>(e: &Env, key: &StorageKey) -> Option<V> {
+    let value = e.storage().persistent().get::<_, V>(key);
+    if value.is_some() {
+        e.storage().persistent().extend_ttl(key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+    }
+    value
+}
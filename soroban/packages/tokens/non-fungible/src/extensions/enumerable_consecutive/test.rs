@@ -0,0 +1,333 @@
+#![cfg(test)]
+
+extern crate std;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use soroban_sdk::{contract, testutils::Address as _, vec, Address, Env, Vec};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    enumerable_consecutive::EnumerableConsecutive, Base, ContractOverrides, TokenId,
+    MAX_BATCH_MINT_RECIPIENTS,
+};
+
+#[contract]
+struct MockContract;
+
+fn global_tokens(e: &Env) -> std::vec::Vec<TokenId> {
+    (0..EnumerableConsecutive::total_supply(e))
+        .map(|index| EnumerableConsecutive::get_token_id(e, index))
+        .collect()
+}
+
+fn owner_tokens(e: &Env, owner: &Address) -> std::vec::Vec<TokenId> {
+    (0..Base::balance(e, owner))
+        .map(|index| EnumerableConsecutive::get_owner_token_id(e, owner, index))
+        .collect()
+}
+
+fn sorted(tokens: std::vec::Vec<TokenId>) -> BTreeSet<TokenId> {
+    let set: BTreeSet<TokenId> = tokens.iter().copied().collect();
+    // no duplicates
+    assert_eq!(set.len(), tokens.len());
+    set
+}
+
+#[test]
+fn batch_mint_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner1 = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        assert_eq!(EnumerableConsecutive::batch_mint(&e, &owner1, 3), 2);
+        assert_eq!(EnumerableConsecutive::batch_mint(&e, &owner2, 2), 4);
+        assert_eq!(EnumerableConsecutive::batch_mint(&e, &owner1, 2), 6);
+
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 7);
+        assert_eq!(global_tokens(&e), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(owner_tokens(&e, &owner1), [0, 1, 2, 5, 6]);
+        assert_eq!(owner_tokens(&e, &owner2), [3, 4]);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 4), owner2);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 6), owner1);
+    });
+}
+
+#[test]
+fn batch_mint_to_many_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner1 = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let recipients = vec![&e, (owner1.clone(), 2), (owner2.clone(), 3), (owner1.clone(), 1)];
+        assert_eq!(EnumerableConsecutive::batch_mint_to_many(&e, &recipients), 5);
+
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 6);
+        assert_eq!(global_tokens(&e), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(owner_tokens(&e, &owner1), [0, 1, 5]);
+        assert_eq!(owner_tokens(&e, &owner2), [2, 3, 4]);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_consecutive_mints(&[(&owner1, 0, 1), (&owner2, 2, 4), (&owner1, 5, 5)]);
+    });
+}
+
+#[test]
+fn batch_mint_to_many_with_max_recipients_works() {
+    let e = Env::default();
+    e.cost_estimate().budget().reset_unlimited();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let mut recipients = Vec::new(&e);
+        for _ in 0..MAX_BATCH_MINT_RECIPIENTS {
+            recipients.push_back((owner.clone(), 1));
+        }
+        let last_id = TokenId::from(MAX_BATCH_MINT_RECIPIENTS) - 1;
+        assert_eq!(EnumerableConsecutive::batch_mint_to_many(&e, &recipients), last_id);
+        assert_eq!(EnumerableConsecutive::total_supply(&e), last_id + 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn batch_mint_to_many_with_too_many_recipients_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let mut recipients = Vec::new(&e);
+        for _ in 0..=MAX_BATCH_MINT_RECIPIENTS {
+            recipients.push_back((owner.clone(), 1));
+        }
+        EnumerableConsecutive::batch_mint_to_many(&e, &recipients);
+    });
+}
+
+#[test]
+fn transfer_in_the_middle_of_a_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 5);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::transfer(&e, &owner, &recipient, 2);
+
+        // the last token of the owner takes the place of the transferred one
+        assert_eq!(owner_tokens(&e, &owner), [0, 1, 4, 3]);
+        assert_eq!(owner_tokens(&e, &recipient), [2]);
+        assert_eq!(global_tokens(&e), [0, 1, 2, 3, 4]);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 2), recipient);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 3), owner);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_transfer(&owner, &recipient, 2);
+    });
+}
+
+#[test]
+fn transfer_from_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 3);
+        EnumerableConsecutive::approve(&e, &owner, &spender, 0, 1000);
+
+        EnumerableConsecutive::transfer_from(&e, &spender, &owner, &recipient, 0);
+
+        assert_eq!(owner_tokens(&e, &owner), [2, 1]);
+        assert_eq!(owner_tokens(&e, &recipient), [0]);
+    });
+}
+
+#[test]
+fn burn_in_the_middle_of_a_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner1 = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner1, 4);
+        EnumerableConsecutive::batch_mint(&e, &owner2, 3);
+    });
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::burn(&e, &owner1, 1);
+
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 6);
+        assert_eq!(global_tokens(&e), [0, 6, 2, 3, 4, 5]);
+        assert_eq!(owner_tokens(&e, &owner1), [0, 3, 2]);
+        assert_eq!(owner_tokens(&e, &owner2), [4, 5, 6]);
+        assert_eq!(EnumerableConsecutive::owner_of(&e, 2), owner1);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_burn(&owner1, 1);
+    });
+}
+
+#[test]
+fn burn_from_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 3);
+        EnumerableConsecutive::approve(&e, &owner, &spender, 2, 1000);
+
+        EnumerableConsecutive::burn_from(&e, &spender, &owner, 2);
+
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 2);
+        assert_eq!(global_tokens(&e), [0, 1]);
+        assert_eq!(owner_tokens(&e, &owner), [0, 1]);
+    });
+}
+
+#[test]
+fn batch_mint_after_burning_a_whole_batch_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 3);
+    });
+
+    for token_id in 0..3 {
+        e.as_contract(&address, || {
+            EnumerableConsecutive::burn(&e, &owner, token_id);
+        });
+    }
+
+    e.as_contract(&address, || {
+        assert_eq!(EnumerableConsecutive::total_supply(&e), 0);
+
+        EnumerableConsecutive::batch_mint(&e, &owner, 2);
+
+        assert_eq!(global_tokens(&e), [3, 4]);
+        assert_eq!(owner_tokens(&e, &owner), [3, 4]);
+    });
+}
+
+#[test]
+fn mixed_operations_keep_enumerations_consistent() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.cost_estimate().budget().reset_unlimited();
+    let address = e.register(MockContract, ());
+    let owners: std::vec::Vec<Address> = (0..3).map(|_| Address::generate(&e)).collect();
+
+    // owner index of every live token
+    let mut model: BTreeMap<TokenId, usize> = BTreeMap::new();
+    let mut seed: u64 = 42;
+    let mut next = |bound: usize| {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((seed >> 33) as usize) % bound
+    };
+
+    for _ in 0..60 {
+        // every operation runs in its own frame, as it requires authorization
+        e.as_contract(&address, || match next(5) {
+            0 => {
+                let to = next(owners.len());
+                let amount = 1 + next(4) as TokenId;
+                let last_id = EnumerableConsecutive::batch_mint(&e, &owners[to], amount);
+                for token_id in (last_id + 1 - amount)..=last_id {
+                    model.insert(token_id, to);
+                }
+            }
+            1 => {
+                let mut recipients = Vec::new(&e);
+                let mut minted = std::vec::Vec::new();
+                for _ in 0..1 + next(3) {
+                    let to = next(owners.len());
+                    let amount = 1 + next(3) as TokenId;
+                    recipients.push_back((owners[to].clone(), amount));
+                    minted.extend((0..amount).map(|_| to));
+                }
+                let last_id = EnumerableConsecutive::batch_mint_to_many(&e, &recipients);
+                let first_id = last_id + 1 - minted.len() as TokenId;
+                for (token_id, to) in (first_id..=last_id).zip(minted) {
+                    model.insert(token_id, to);
+                }
+            }
+            2 if !model.is_empty() => {
+                let (&token_id, &from) = model.iter().nth(next(model.len())).unwrap();
+                EnumerableConsecutive::burn(&e, &owners[from], token_id);
+                model.remove(&token_id);
+            }
+            _ if !model.is_empty() => {
+                let (&token_id, &from) = model.iter().nth(next(model.len())).unwrap();
+                let to = (from + 1 + next(owners.len() - 1)) % owners.len();
+                EnumerableConsecutive::transfer(&e, &owners[from], &owners[to], token_id);
+                model.insert(token_id, to);
+            }
+            _ => {}
+        });
+
+        e.as_contract(&address, || {
+            assert_eq!(EnumerableConsecutive::total_supply(&e) as usize, model.len());
+            assert_eq!(sorted(global_tokens(&e)), model.keys().copied().collect());
+            for (i, owner) in owners.iter().enumerate() {
+                let expected: BTreeSet<TokenId> =
+                    model.iter().filter(|(_, &o)| o == i).map(|(&t, _)| t).collect();
+                assert_eq!(sorted(owner_tokens(&e, owner)), expected);
+            }
+            for (&token_id, &owner) in model.iter() {
+                assert_eq!(EnumerableConsecutive::owner_of(&e, token_id), owners[owner]);
+            }
+        });
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #309)")]
+fn get_token_id_out_of_bounds_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 3);
+        EnumerableConsecutive::get_token_id(&e, 3);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #308)")]
+fn get_owner_token_id_out_of_bounds_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 3);
+        EnumerableConsecutive::get_owner_token_id(&e, &other, 0);
+    });
+}
@@ -1,3 +1,14 @@
+pub mod allowlist;
+pub mod approvals;
+pub mod batch_transfer;
 pub mod burnable;
+pub mod capped;
 pub mod consecutive;
 pub mod enumerable;
+pub mod enumerable_consecutive;
+pub mod rentable;
+pub mod reveal;
+pub mod royalties;
+pub mod safe_transfer;
+pub mod soulbound;
+pub mod uri_storage;
@@ -0,0 +1,137 @@
+//! # Rentable Extension for Non-Fungible Token
+//!
+//! The `rentable` module adds a `user` role to the tokens, mirroring ERC-4907.
+//! The owner (or an approved account) can grant the `user` role to an account
+//! until a given ledger, without transferring the ownership of the token. This
+//! is useful for lending tokens, e.g. for game sessions.
+//!
+//! The user entry lives in temporary storage and expires together with
+//! `expires_ledger`, similarly to the approvals. It is also cleared
+//! automatically whenever the token is transferred or burned.
+//!
+//! ## Notes
+//!
+//! - The `user` role has no effect in this crate. It is up to the consumers
+//!   (games, dApps, etc.) to query [`NonFungibleRentable::user_of()`] and
+//!   grant the corresponding rights.
+//! - `set_user` is dispatched through [`crate::ContractOverrides`], so the
+//!   owner of the token is resolved by the contract type, e.g. with
+//!   [`crate::consecutive::Consecutive::set_user`] for the `Consecutive` and
+//!   `EnumerableConsecutive` contract types, as ownership is tracked
+//!   differently.
+pub mod storage;
+pub use storage::{StorageKey, UserData};
+
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{NonFungibleToken, TokenId};
+
+/// Rentable Trait for Non-Fungible Token
+///
+/// The `NonFungibleRentable` trait extends the `NonFungibleToken` trait to
+/// provide the capability to grant the `user` role of a token to an account
+/// for a limited time. This trait is designed to be used in conjunction with
+/// the `NonFungibleToken` trait.
+///
+/// # Notes
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`NonFungibleRentable`] trait for your Smart Contract,
+/// you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl NonFungibleRentable for MyContract {
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait NonFungibleRentable: NonFungibleToken {
+    /// Grants the `user` role of `token_id` to `user` until `expires_ledger`.
+    /// Any previous user of the token is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `caller` - The owner of the token, or an account approved for it.
+    /// * `token_id` - Token id as a number.
+    /// * `user` - The account receiving the `user` role.
+    /// * `expires_ledger` - The ledger number at which the `user` role
+    ///   expires.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::NonExistentToken`] - If the token
+    ///   does not exist.
+    /// * [`crate::NonFungibleTokenError::InsufficientApproval`] - If `caller`
+    ///   is neither the owner of the token nor approved for it.
+    /// * [`crate::NonFungibleTokenError::InvalidLiveUntilLedger`] - If
+    ///   `expires_ledger` is less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: TokenId]`
+    /// * data - `[user: Address, expires_ledger: u32]`
+    fn set_user(e: &Env, caller: Address, token_id: TokenId, user: Address, expires_ledger: u32);
+
+    /// Returns the user of `token_id`, or `None` if there is no user or the
+    /// `user` role has expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn user_of(e: &Env, token_id: TokenId) -> Option<Address>;
+
+    /// Returns the ledger number at which the `user` role of `token_id`
+    /// expires, or `0` if there is no user.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    fn user_expires(e: &Env, token_id: TokenId) -> u32;
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating the user of a token is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token_id` - The identifier of the token.
+/// * `user` - The account receiving the `user` role.
+/// * `expires_ledger` - The ledger number at which the `user` role expires.
+///
+/// # Events
+///
+/// * topics - `["update_user", token_id: TokenId]`
+/// * data - `[user: Address, expires_ledger: u32]`
+pub fn emit_update_user(e: &Env, token_id: TokenId, user: &Address, expires_ledger: u32) {
+    let topics = (Symbol::new(e, "update_user"), token_id);
+    e.events().publish(topics, (user, expires_ledger))
+}
+
+/// Emits an event indicating the user of a token is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token_id` - The identifier of the token.
+///
+/// # Events
+///
+/// * topics - `["remove_user", token_id: TokenId]`
+/// * data - `[]`
+pub fn emit_remove_user(e: &Env, token_id: TokenId) {
+    let topics = (Symbol::new(e, "remove_user"), token_id);
+    e.events().publish(topics, ())
+}
@@ -0,0 +1,216 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use crate::{
+    consecutive::Consecutive,
+    rentable::{emit_remove_user, emit_update_user},
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Storage container for the user of a token and the ledger number at which
+/// the `user` role expires.
+#[contracttype]
+pub struct UserData {
+    pub user: Address,
+    pub expires_ledger: u32,
+}
+
+/// Storage keys for the data associated with the `rentable` extension
+#[contracttype]
+pub enum StorageKey {
+    User(TokenId),
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the user of `token_id`, or `None` if there is no user or the
+    /// `user` role has expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn user_of(e: &Env, token_id: TokenId) -> Option<Address> {
+        let key = StorageKey::User(token_id);
+
+        if let Some(user_data) = e.storage().temporary().get::<_, UserData>(&key) {
+            if user_data.expires_ledger < e.ledger().sequence() {
+                return None; // Return None if the `user` role expired
+            }
+            Some(user_data.user)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ledger number at which the `user` role of `token_id`
+    /// expires, or `0` if there is no user.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn user_expires(e: &Env, token_id: TokenId) -> u32 {
+        e.storage()
+            .temporary()
+            .get::<_, UserData>(&StorageKey::User(token_id))
+            .map(|user_data| user_data.expires_ledger)
+            .unwrap_or(0)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Grants the `user` role of `token_id` to `user` until `expires_ledger`.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `caller` - The owner of the token, or an account approved for it.
+    /// * `token_id` - The identifier of the token.
+    /// * `user` - The account receiving the `user` role.
+    /// * `expires_ledger` - The ledger number at which the `user` role
+    ///   expires.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::owner_of`] errors.
+    /// * refer to [`Base::set_user_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: TokenId]`
+    /// * data - `[user: Address, expires_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `caller` is required.
+    pub fn set_user(
+        e: &Env,
+        caller: &Address,
+        token_id: TokenId,
+        user: &Address,
+        expires_ledger: u32,
+    ) {
+        caller.require_auth();
+
+        let owner = Base::owner_of(e, token_id);
+        Base::set_user_for_owner(e, &owner, caller, token_id, user, expires_ledger);
+    }
+
+    // ################## LOW-LEVEL HELPERS ##################
+
+    /// Low-level function for granting the `user` role of `token_id`, without
+    /// checking its ownership and without handling authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The owner of the token.
+    /// * `caller` - The owner of the token, or an account approved for it.
+    /// * `token_id` - The identifier of the token.
+    /// * `user` - The account receiving the `user` role.
+    /// * `expires_ledger` - The ledger number at which the `user` role
+    ///   expires.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::check_spender_approval`] errors.
+    /// * [`NonFungibleTokenError::InvalidLiveUntilLedger`] - If
+    ///   `expires_ledger` is less than the current ledger number.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: TokenId]`
+    /// * data - `[user: Address, expires_ledger: u32]`
+    pub fn set_user_for_owner(
+        e: &Env,
+        owner: &Address,
+        caller: &Address,
+        token_id: TokenId,
+        user: &Address,
+        expires_ledger: u32,
+    ) {
+        Base::check_spender_approval(e, caller, owner, token_id);
+
+        if expires_ledger < e.ledger().sequence() {
+            panic_with_error!(e, NonFungibleTokenError::InvalidLiveUntilLedger);
+        }
+
+        let key = StorageKey::User(token_id);
+
+        let user_data = UserData { user: user.clone(), expires_ledger };
+
+        e.storage().temporary().set(&key, &user_data);
+
+        let live_for = expires_ledger - e.ledger().sequence();
+
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+
+        emit_update_user(e, token_id, user, expires_ledger);
+    }
+
+    /// Low-level function for removing the user of `token_id`, if any,
+    /// without handling authorization. It is called on every transfer and
+    /// burn, so the `user` role does not survive a change of ownership.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["remove_user", token_id: TokenId]`
+    /// * data - `[]`
+    ///
+    /// Emitted only if the token had a user.
+    pub fn remove_user(e: &Env, token_id: TokenId) {
+        let key = StorageKey::User(token_id);
+
+        if e.storage().temporary().has(&key) {
+            e.storage().temporary().remove(&key);
+            emit_remove_user(e, token_id);
+        }
+    }
+}
+
+impl Consecutive {
+    /// Grants the `user` role of `token_id` to `user` until `expires_ledger`.
+    /// Same as [`Base::set_user`], but resolving the owner with
+    /// [`Consecutive::owner_of`].
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `caller` - The owner of the token, or an account approved for it.
+    /// * `token_id` - The identifier of the token.
+    /// * `user` - The account receiving the `user` role.
+    /// * `expires_ledger` - The ledger number at which the `user` role
+    ///   expires.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::owner_of`] errors.
+    /// * refer to [`Base::set_user_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["update_user", token_id: TokenId]`
+    /// * data - `[user: Address, expires_ledger: u32]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `caller` is required.
+    pub fn set_user(
+        e: &Env,
+        caller: &Address,
+        token_id: TokenId,
+        user: &Address,
+        expires_ledger: u32,
+    ) {
+        caller.require_auth();
+
+        let owner = Consecutive::owner_of(e, token_id);
+        Base::set_user_for_owner(e, &owner, caller, token_id, user, expires_ledger);
+    }
+}
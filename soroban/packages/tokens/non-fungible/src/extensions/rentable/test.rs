@@ -0,0 +1,283 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger as _},
+    Address, Env,
+};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{
+    consecutive::Consecutive, enumerable_consecutive::EnumerableConsecutive, Base,
+    ContractOverrides,
+};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn set_user_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 1000);
+
+        assert_eq!(Base::user_of(&e, token_id), Some(user.clone()));
+        assert_eq!(Base::user_expires(&e, token_id), 1000);
+        // the ownership does not change
+        assert_eq!(Base::owner_of(&e, token_id), owner);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_non_fungible_mint(&owner, token_id);
+        event_assert.assert_update_user(token_id, &user, 1000);
+    });
+}
+
+#[test]
+fn user_of_without_user_is_none() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        assert_eq!(Base::user_of(&e, token_id), None);
+        assert_eq!(Base::user_expires(&e, token_id), 0);
+    });
+}
+
+#[test]
+fn set_user_by_approved_account_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &approved, token_id, 1000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::set_user(&e, &approved, token_id, &user, 500);
+
+        assert_eq!(Base::user_of(&e, token_id), Some(user.clone()));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn set_user_by_unauthorized_account_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let caller = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &caller, token_id, &user, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn set_user_with_invalid_expires_ledger_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        e.ledger().set_sequence_number(10);
+
+        Base::set_user(&e, &owner, token_id, &user, 1);
+    });
+}
+
+#[test]
+fn user_of_after_expiry_is_none() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 5);
+
+        e.ledger().set_sequence_number(5);
+        assert_eq!(Base::user_of(&e, token_id), Some(user.clone()));
+
+        e.ledger().set_sequence_number(6);
+        assert_eq!(Base::user_of(&e, token_id), None);
+    });
+}
+
+#[test]
+fn transfer_clears_user() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 1000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::transfer(&e, &owner, &recipient, token_id);
+
+        assert_eq!(Base::user_of(&e, token_id), None);
+        assert_eq!(Base::user_expires(&e, token_id), 0);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_remove_user(token_id);
+    });
+}
+
+#[test]
+fn transfer_without_user_emits_no_remove_user_event() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::transfer(&e, &owner, &recipient, token_id);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+    });
+}
+
+#[test]
+fn burn_clears_user() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_user(&e, &owner, token_id, &user, 1000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::burn(&e, &owner, token_id);
+
+        assert_eq!(Base::user_of(&e, token_id), None);
+    });
+}
+
+#[test]
+fn consecutive_set_user_and_transfer_work() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+        Consecutive::set_user(&e, &owner, 5, &user, 1000);
+
+        assert_eq!(Base::user_of(&e, 5), Some(user.clone()));
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::transfer(&e, &owner, &recipient, 5);
+
+        assert_eq!(Base::user_of(&e, 5), None);
+        assert_eq!(Consecutive::owner_of(&e, 5), recipient);
+    });
+}
+
+#[test]
+fn consecutive_set_user_through_contract_overrides_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+        // token 5 has never left its batch, so it has no recorded owner
+        <Consecutive as ContractOverrides>::set_user(&e, &owner, 5, &user, 1000);
+
+        assert_eq!(Base::user_of(&e, 5), Some(user.clone()));
+        assert_eq!(Base::user_expires(&e, 5), 1000);
+    });
+
+    e.as_contract(&address, || {
+        Consecutive::approve(&e, &owner, &spender, 7, 1000);
+    });
+
+    e.as_contract(&address, || {
+        <Consecutive as ContractOverrides>::set_user(&e, &spender, 7, &user, 500);
+
+        assert_eq!(Base::user_of(&e, 7), Some(user.clone()));
+        assert_eq!(Consecutive::owner_of(&e, 7), owner);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn consecutive_set_user_by_unauthorized_account_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+        <Consecutive as ContractOverrides>::set_user(&e, &other, 5, &user, 1000);
+    });
+}
+
+#[test]
+fn enumerable_consecutive_set_user_through_contract_overrides_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        EnumerableConsecutive::batch_mint(&e, &owner, 10);
+        <EnumerableConsecutive as ContractOverrides>::set_user(&e, &owner, 3, &user, 1000);
+
+        assert_eq!(Base::user_of(&e, 3), Some(user.clone()));
+    });
+}
@@ -0,0 +1,62 @@
+//! # Reveal Extension for Non-Fungible Token
+//!
+//! The `reveal` module supports delayed reveals: the collection launches with
+//! a single placeholder URI (e.g. a hidden image), returned for every token,
+//! and the real base URI is set later, once the tokens are minted.
+//!
+//! ## Implementation Notes
+//!
+//! - **token_uri**: As long as a placeholder URI is set and the collection is
+//!   not revealed, [`crate::Base::token_uri`] and
+//!   [`crate::consecutive::Consecutive::token_uri`] return the placeholder
+//!   URI for every token, including those with a stored URI.
+//! - **Provenance**: A provenance hash can be stored at launch with
+//!   [`crate::Base::set_provenance_hash`]. It commits to the real base URI,
+//!   which has to match the hash on reveal. The commitment cannot be changed
+//!   once it is set.
+//! - **Random offset**: On reveal, an offset in `[0, collection_size)` can be
+//!   drawn from `e.prng()`, and the token `token_id` then points to the
+//!   metadata `(token_id + offset) % collection_size`. As the offset is only
+//!   known after the mint, minters cannot pick the ids mapped to the rarest
+//!   items.
+//! - **Events**: Setting the placeholder and revealing emit a
+//!   `batch_metadata_update` event, so that indexers can refresh the metadata
+//!   of every token.
+//!
+//! Unlike other extensions, the `reveal` extension does not provide a separate
+//! trait, since [`crate::NonFungibleToken::token_uri`] already exposes the
+//! placeholder URI.
+//!
+//! ## Security Warning
+//!
+//! The Soroban PRNG is seeded by the network, and validators could in theory
+//! influence the offset. It is good enough to prevent minters from front-running
+//! the mapping, but should not protect high-value outcomes.
+pub mod storage;
+pub use storage::{RevealData, StorageKey};
+
+mod test;
+
+use soroban_sdk::{Env, Symbol};
+
+use crate::TokenId;
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating the collection is revealed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `collection_size` - The number of items the offset applies to, `0` if
+///   no offset is applied.
+/// * `offset` - The random offset applied to the token ids.
+///
+/// # Events
+///
+/// * topics - `["reveal"]`
+/// * data - `[collection_size: TokenId, offset: TokenId]`
+pub fn emit_reveal(e: &Env, collection_size: TokenId, offset: TokenId) {
+    let topics = (Symbol::new(e, "reveal"),);
+    e.events().publish(topics, (collection_size, offset))
+}
@@ -0,0 +1,215 @@
+use soroban_sdk::{contracttype, panic_with_error, Bytes, BytesN, Env, String};
+
+use crate::{
+    non_fungible::{emit_batch_metadata_update, MAX_BASE_URI_LEN},
+    reveal::emit_reveal,
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Storage container for the random offset applied on reveal.
+#[contracttype]
+pub struct RevealData {
+    pub collection_size: TokenId,
+    pub offset: TokenId,
+}
+
+/// Storage keys for the data associated with the `reveal` extension
+#[contracttype]
+pub enum StorageKey {
+    PlaceholderUri,
+    ProvenanceHash,
+    Reveal,
+}
+
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the placeholder URI, or `None` if there is none or the
+    /// collection is revealed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn placeholder_uri(e: &Env) -> Option<String> {
+        e.storage().instance().get(&StorageKey::PlaceholderUri)
+    }
+
+    /// Returns the provenance hash stored at launch, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn provenance_hash(e: &Env) -> Option<BytesN<32>> {
+        e.storage().instance().get(&StorageKey::ProvenanceHash)
+    }
+
+    /// Returns `true` if the collection is revealed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn is_revealed(e: &Env) -> bool {
+        e.storage().instance().has(&StorageKey::Reveal)
+    }
+
+    /// Returns the id of the metadata `token_id` points to, i.e.
+    /// `(token_id + offset) % collection_size`, or `token_id` if no offset
+    /// was applied on reveal.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    pub fn revealed_token_id(e: &Env, token_id: TokenId) -> TokenId {
+        let Some(data) = e.storage().instance().get::<_, RevealData>(&StorageKey::Reveal) else {
+            return token_id;
+        };
+        if data.collection_size == 0 {
+            return token_id;
+        }
+
+        // Computed without overflow, as `id` and `offset` are both lower than
+        // `collection_size`.
+        let id = token_id % data.collection_size;
+        if id >= data.collection_size - data.offset {
+            id - (data.collection_size - data.offset)
+        } else {
+            id + data.offset
+        }
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the placeholder URI, returned for every token until the
+    /// collection is revealed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `uri` - The placeholder URI.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::AlreadyRevealed`] - If the collection is
+    ///   revealed.
+    /// * [`NonFungibleTokenError::BaseUriMaxLenExceeded`] - If the length of
+    ///   `uri` exceeds the maximum allowed.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_metadata_update"]`
+    /// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it most likely from the constructor, or from another function
+    /// with admin-only authorization.
+    pub fn set_placeholder_uri(e: &Env, uri: String) {
+        if Base::is_revealed(e) {
+            panic_with_error!(e, NonFungibleTokenError::AlreadyRevealed);
+        }
+        if uri.len() as usize > MAX_BASE_URI_LEN {
+            panic_with_error!(e, NonFungibleTokenError::BaseUriMaxLenExceeded);
+        }
+
+        e.storage().instance().set(&StorageKey::PlaceholderUri, &uri);
+
+        emit_batch_metadata_update(e, 0, TokenId::MAX);
+    }
+
+    /// Stores the provenance hash, i.e. the `sha256` hash of the base URI
+    /// the collection will be revealed with.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `hash` - The provenance hash.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::AlreadyRevealed`] - If the collection is
+    ///   revealed.
+    /// * [`NonFungibleTokenError::ProvenanceAlreadySet`] - If the provenance
+    ///   hash is already set.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it most likely from the constructor, or from another function
+    /// with admin-only authorization, before the mint starts.
+    pub fn set_provenance_hash(e: &Env, hash: &BytesN<32>) {
+        if Base::is_revealed(e) {
+            panic_with_error!(e, NonFungibleTokenError::AlreadyRevealed);
+        }
+        if Base::provenance_hash(e).is_some() {
+            panic_with_error!(e, NonFungibleTokenError::ProvenanceAlreadySet);
+        }
+
+        e.storage().instance().set(&StorageKey::ProvenanceHash, hash);
+    }
+
+    /// Reveals the collection: sets the real base URI, removes the
+    /// placeholder URI and, if `collection_size` is not `0`, draws a random
+    /// offset applied to the token ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `base_uri` - The real base URI.
+    /// * `collection_size` - The number of items the offset applies to. If
+    ///   `0`, no offset is applied.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::AlreadyRevealed`] - If the collection is
+    ///   already revealed.
+    /// * [`NonFungibleTokenError::BaseUriMaxLenExceeded`] - If the length of
+    ///   `base_uri` exceeds the maximum allowed.
+    /// * [`NonFungibleTokenError::InvalidProvenance`] - If a provenance hash
+    ///   is set and does not match `sha256(base_uri)`.
+    /// * refer to [`Base::update_base_uri`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_metadata_update"]`
+    /// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+    ///
+    /// * topics - `["reveal"]`
+    /// * data - `[collection_size: TokenId, offset: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it from another function with admin-only authorization.
+    pub fn reveal(e: &Env, base_uri: String, collection_size: TokenId) {
+        if Base::is_revealed(e) {
+            panic_with_error!(e, NonFungibleTokenError::AlreadyRevealed);
+        }
+        let len = base_uri.len() as usize;
+        if len > MAX_BASE_URI_LEN {
+            panic_with_error!(e, NonFungibleTokenError::BaseUriMaxLenExceeded);
+        }
+
+        if let Some(hash) = Base::provenance_hash(e) {
+            let uri = &mut [0u8; MAX_BASE_URI_LEN];
+            base_uri.copy_into_slice(&mut uri[..len]);
+            let computed = e.crypto().sha256(&Bytes::from_slice(e, &uri[..len])).to_bytes();
+            if computed != hash {
+                panic_with_error!(e, NonFungibleTokenError::InvalidProvenance);
+            }
+        }
+
+        let offset = if collection_size > 0 {
+            (e.prng().gen::<u64>() as TokenId) % collection_size
+        } else {
+            0
+        };
+
+        Base::update_base_uri(e, base_uri);
+        e.storage().instance().remove(&StorageKey::PlaceholderUri);
+        e.storage().instance().set(&StorageKey::Reveal, &RevealData { collection_size, offset });
+
+        emit_reveal(e, collection_size, offset);
+    }
+}
@@ -0,0 +1,198 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Bytes, BytesN, Env, String};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{consecutive::Consecutive, Base, TokenId};
+
+#[contract]
+struct MockContract;
+
+const PLACEHOLDER: &str = "ipfs://hidden.json";
+const REVEALED: &str = "https://smth.com/";
+
+fn set_metadata(e: &Env) {
+    Base::set_metadata(
+        e,
+        String::from_str(e, "ipfs://unused/"),
+        String::from_str(e, "My NFT collection"),
+        String::from_str(e, "NFT"),
+    );
+    Base::set_placeholder_uri(e, String::from_str(e, PLACEHOLDER));
+}
+
+fn provenance(e: &Env, uri: &str) -> BytesN<32> {
+    e.crypto().sha256(&Bytes::from_slice(e, uri.as_bytes())).to_bytes()
+}
+
+#[test]
+fn placeholder_uri_before_reveal() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        assert!(!Base::is_revealed(&e));
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, PLACEHOLDER));
+    });
+}
+
+#[test]
+fn placeholder_uri_hides_stored_token_uri() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_token_uri(&e, token_id, String::from_str(&e, "ipfs://secret"));
+
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, PLACEHOLDER));
+    });
+}
+
+#[test]
+fn reveal_without_offset_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        set_metadata(&e);
+        Base::sequential_mint(&e, &owner);
+        Base::sequential_mint(&e, &owner)
+    });
+
+    e.as_contract(&address, || {
+        Base::reveal(&e, String::from_str(&e, REVEALED), 0);
+
+        assert!(Base::is_revealed(&e));
+        assert_eq!(Base::placeholder_uri(&e), None);
+        assert_eq!(Base::revealed_token_id(&e, token_id), token_id);
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, "https://smth.com/1"));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_batch_metadata_update(0, TokenId::MAX);
+        event_assert.assert_reveal(0, 0);
+    });
+}
+
+#[test]
+fn reveal_with_offset_shifts_token_ids() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        for _ in 0..10 {
+            Base::sequential_mint(&e, &owner);
+        }
+        Base::reveal(&e, String::from_str(&e, REVEALED), 10);
+
+        // the mapping is a permutation of the collection
+        let mut seen = [false; 10];
+        for token_id in 0..10 {
+            let revealed = Base::revealed_token_id(&e, token_id);
+            assert!(revealed < 10);
+            assert!(!seen[revealed as usize]);
+            seen[revealed as usize] = true;
+        }
+
+        let offset = Base::revealed_token_id(&e, 0);
+        assert_eq!(Base::revealed_token_id(&e, 9), (9 + offset) % 10);
+    });
+}
+
+#[test]
+fn reveal_with_matching_provenance_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Base::set_provenance_hash(&e, &provenance(&e, REVEALED));
+
+        Base::reveal(&e, String::from_str(&e, REVEALED), 0);
+
+        assert!(Base::is_revealed(&e));
+        assert_eq!(Base::provenance_hash(&e), Some(provenance(&e, REVEALED)));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #326)")]
+fn reveal_with_wrong_provenance_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Base::set_provenance_hash(&e, &provenance(&e, REVEALED));
+
+        Base::reveal(&e, String::from_str(&e, "https://other.com/"), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #325)")]
+fn set_provenance_hash_twice_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_provenance_hash(&e, &provenance(&e, REVEALED));
+        Base::set_provenance_hash(&e, &provenance(&e, "https://other.com/"));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")]
+fn reveal_twice_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Base::reveal(&e, String::from_str(&e, REVEALED), 0);
+        Base::reveal(&e, String::from_str(&e, REVEALED), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")]
+fn set_placeholder_uri_after_reveal_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Base::reveal(&e, String::from_str(&e, REVEALED), 0);
+        Base::set_placeholder_uri(&e, String::from_str(&e, PLACEHOLDER));
+    });
+}
+
+#[test]
+fn consecutive_token_uri_respects_reveal() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Consecutive::batch_mint(&e, &owner, 5);
+
+        assert_eq!(Consecutive::token_uri(&e, 3), String::from_str(&e, PLACEHOLDER));
+
+        Base::reveal(&e, String::from_str(&e, REVEALED), 0);
+
+        assert_eq!(Consecutive::token_uri(&e, 3), String::from_str(&e, "https://smth.com/3"));
+    });
+}
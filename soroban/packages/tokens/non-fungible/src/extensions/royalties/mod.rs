@@ -0,0 +1,136 @@
+//! # Royalties Extension for Non-Fungible Token
+//!
+//! The `royalties` module provides a standardized way to retrieve royalty
+//! payment information, mirroring ERC-2981. Marketplaces can query
+//! [`NonFungibleRoyalties::royalty_info()`] with the sale price of a token to
+//! learn who should receive a royalty and how much.
+//!
+//! Royalties are expressed in basis points (1/100th of a percent), with
+//! [`FEE_DENOMINATOR`] representing 100%. A collection-wide default royalty
+//! can be set, and overridden for individual tokens.
+//!
+//! ## Notes
+//!
+//! - Royalty payment is NOT enforced by this extension. It only signals the
+//!   expected royalty, and it is up to marketplaces to honor it.
+//! - The setters in [`crate::Base`] intentionally lack authorization controls.
+//!   It is the responsibility of the implementer to restrict who can change
+//!   royalties.
+pub mod storage;
+pub use storage::{RoyaltyInfo, StorageKey};
+
+mod test;
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{NonFungibleToken, TokenId};
+
+/// The denominator of the royalty fraction, equivalent to 100%.
+pub const FEE_DENOMINATOR: u32 = 10_000;
+
+/// Royalties Trait for Non-Fungible Token
+///
+/// The `NonFungibleRoyalties` trait extends the `NonFungibleToken` trait to
+/// provide the capability to signal royalty information for the tokens. This
+/// trait is designed to be used in conjunction with the `NonFungibleToken`
+/// trait, and is compatible with every contract type.
+///
+/// Setting royalties is not part of this trait, since the function signatures
+/// and access control may change depending on the implementation. We do
+/// provide [`crate::Base::set_default_royalty`],
+/// [`crate::Base::set_token_royalty`] and
+/// [`crate::Base::remove_token_royalty`] for this purpose.
+///
+/// # Notes
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`NonFungibleRoyalties`] trait for your Smart Contract,
+/// you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl NonFungibleRoyalties for MyContract {
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait NonFungibleRoyalties: NonFungibleToken {
+    /// Returns the royalty receiver and the royalty amount owed for selling
+    /// `token_id` at `sale_price`. The token-specific royalty is used if set,
+    /// otherwise the collection-wide default applies.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - Token id as a number.
+    /// * `sale_price` - The sale price of the token, in any unit of exchange.
+    ///   The royalty amount is denominated in the same unit.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::RoyaltyNotSet`] - If neither a
+    ///   token-specific nor a default royalty is set.
+    /// * [`crate::NonFungibleTokenError::InvalidSalePrice`] - If `sale_price`
+    ///   is negative.
+    /// * [`crate::NonFungibleTokenError::MathOverflow`] - If the royalty
+    ///   amount overflows.
+    fn royalty_info(e: &Env, token_id: TokenId, sale_price: i128) -> (Address, i128);
+}
+
+// ################## EVENTS ##################
+
+/// Emits an event indicating the collection-wide default royalty is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `receiver` - The address receiving the royalties.
+/// * `basis_points` - The royalty fraction, in basis points.
+///
+/// # Events
+///
+/// * topics - `["set_default_royalty", receiver: Address]`
+/// * data - `[basis_points: u32]`
+pub fn emit_set_default_royalty(e: &Env, receiver: &Address, basis_points: u32) {
+    let topics = (Symbol::new(e, "set_default_royalty"), receiver);
+    e.events().publish(topics, basis_points)
+}
+
+/// Emits an event indicating a token-specific royalty is set.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `receiver` - The address receiving the royalties.
+/// * `token_id` - The identifier of the token.
+/// * `basis_points` - The royalty fraction, in basis points.
+///
+/// # Events
+///
+/// * topics - `["set_token_royalty", receiver: Address, token_id: TokenId]`
+/// * data - `[basis_points: u32]`
+pub fn emit_set_token_royalty(e: &Env, receiver: &Address, token_id: TokenId, basis_points: u32) {
+    let topics = (Symbol::new(e, "set_token_royalty"), receiver, token_id);
+    e.events().publish(topics, basis_points)
+}
+
+/// Emits an event indicating a token-specific royalty is removed.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token_id` - The identifier of the token.
+///
+/// # Events
+///
+/// * topics - `["remove_token_royalty", token_id: TokenId]`
+/// * data - `[]`
+pub fn emit_remove_token_royalty(e: &Env, token_id: TokenId) {
+    let topics = (Symbol::new(e, "remove_token_royalty"), token_id);
+    e.events().publish(topics, ())
+}
@@ -0,0 +1,174 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{
+    royalties::{
+        emit_remove_token_royalty, emit_set_default_royalty, emit_set_token_royalty,
+        FEE_DENOMINATOR,
+    },
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Storage container for the receiver of the royalties and the royalty
+/// fraction in basis points.
+#[contracttype]
+pub struct RoyaltyInfo {
+    pub receiver: Address,
+    pub basis_points: u32,
+}
+
+/// Storage keys for the data associated with the `royalties` extension
+#[contracttype]
+pub enum StorageKey {
+    DefaultRoyalty,
+    TokenRoyalty(TokenId),
+}
+
+// `Royalties` extension is compatible with every contract type, as it does
+// not depend on how ownership is tracked.
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the royalty receiver and the royalty amount owed for selling
+    /// `token_id` at `sale_price`. The token-specific royalty is used if set,
+    /// otherwise the collection-wide default applies.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `sale_price` - The sale price of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidSalePrice`] - If `sale_price` is
+    ///   negative.
+    /// * [`NonFungibleTokenError::RoyaltyNotSet`] - If neither a
+    ///   token-specific nor a default royalty is set.
+    /// * [`NonFungibleTokenError::MathOverflow`] - If the royalty amount
+    ///   overflows.
+    ///
+    /// # Notes
+    ///
+    /// This function does not check whether `token_id` exists.
+    pub fn royalty_info(e: &Env, token_id: TokenId, sale_price: i128) -> (Address, i128) {
+        if sale_price < 0 {
+            panic_with_error!(e, NonFungibleTokenError::InvalidSalePrice);
+        }
+
+        let key = StorageKey::TokenRoyalty(token_id);
+        let royalty = match e.storage().persistent().get::<_, RoyaltyInfo>(&key) {
+            Some(royalty) => {
+                e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+                royalty
+            }
+            None => e
+                .storage()
+                .instance()
+                .get::<_, RoyaltyInfo>(&StorageKey::DefaultRoyalty)
+                .unwrap_or_else(|| panic_with_error!(e, NonFungibleTokenError::RoyaltyNotSet)),
+        };
+
+        let Some(amount) = sale_price.checked_mul(royalty.basis_points as i128) else {
+            panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+        };
+
+        (royalty.receiver, amount / FEE_DENOMINATOR as i128)
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Sets the royalty that applies to every token without a token-specific
+    /// royalty.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `receiver` - The address receiving the royalties.
+    /// * `basis_points` - The royalty fraction, in basis points.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidRoyalty`] - If `basis_points` exceeds
+    ///   [`FEE_DENOMINATOR`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_default_royalty", receiver: Address]`
+    /// * data - `[basis_points: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it most likely from a constructor or from another function with
+    /// admin-only authorization.
+    pub fn set_default_royalty(e: &Env, receiver: &Address, basis_points: u32) {
+        if basis_points > FEE_DENOMINATOR {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRoyalty);
+        }
+
+        let royalty = RoyaltyInfo { receiver: receiver.clone(), basis_points };
+        e.storage().instance().set(&StorageKey::DefaultRoyalty, &royalty);
+
+        emit_set_default_royalty(e, receiver, basis_points);
+    }
+
+    /// Sets the royalty for `token_id`, overriding the default royalty.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `receiver` - The address receiving the royalties.
+    /// * `basis_points` - The royalty fraction, in basis points.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidRoyalty`] - If `basis_points` exceeds
+    ///   [`FEE_DENOMINATOR`].
+    ///
+    /// # Events
+    ///
+    /// * topics - `["set_token_royalty", receiver: Address, token_id: TokenId]`
+    /// * data - `[basis_points: u32]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls, and does not
+    /// check whether `token_id` exists. You want to invoke it most likely from
+    /// another function with admin-only authorization.
+    pub fn set_token_royalty(e: &Env, token_id: TokenId, receiver: &Address, basis_points: u32) {
+        if basis_points > FEE_DENOMINATOR {
+            panic_with_error!(e, NonFungibleTokenError::InvalidRoyalty);
+        }
+
+        let royalty = RoyaltyInfo { receiver: receiver.clone(), basis_points };
+        e.storage().persistent().set(&StorageKey::TokenRoyalty(token_id), &royalty);
+
+        emit_set_token_royalty(e, receiver, token_id, basis_points);
+    }
+
+    /// Removes the token-specific royalty of `token_id`, so that the default
+    /// royalty applies again.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["remove_token_royalty", token_id: TokenId]`
+    /// * data - `[]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it most likely from another function with admin-only
+    /// authorization.
+    pub fn remove_token_royalty(e: &Env, token_id: TokenId) {
+        e.storage().persistent().remove(&StorageKey::TokenRoyalty(token_id));
+
+        emit_remove_token_royalty(e, token_id);
+    }
+}
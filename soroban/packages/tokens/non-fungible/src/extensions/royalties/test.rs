@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+use stellar_event_assertion::EventAssertion;
+
+use crate::Base;
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn default_royalty_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &receiver, 500);
+
+        let (royalty_receiver, amount) = Base::royalty_info(&e, 1, 10_000);
+        assert_eq!(royalty_receiver, receiver);
+        assert_eq!(amount, 500);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_set_default_royalty(&receiver, 500);
+    });
+}
+
+#[test]
+fn token_royalty_overrides_default() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let default_receiver = Address::generate(&e);
+    let token_receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &default_receiver, 500);
+        Base::set_token_royalty(&e, 7, &token_receiver, 1_000);
+
+        let (receiver, amount) = Base::royalty_info(&e, 7, 2_000);
+        assert_eq!(receiver, token_receiver);
+        assert_eq!(amount, 200);
+
+        let (receiver, amount) = Base::royalty_info(&e, 8, 2_000);
+        assert_eq!(receiver, default_receiver);
+        assert_eq!(amount, 100);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_set_default_royalty(&default_receiver, 500);
+        event_assert.assert_set_token_royalty(&token_receiver, 7, 1_000);
+    });
+}
+
+#[test]
+fn remove_token_royalty_falls_back_to_default() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let default_receiver = Address::generate(&e);
+    let token_receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &default_receiver, 500);
+        Base::set_token_royalty(&e, 7, &token_receiver, 1_000);
+        Base::remove_token_royalty(&e, 7);
+
+        let (receiver, amount) = Base::royalty_info(&e, 7, 2_000);
+        assert_eq!(receiver, default_receiver);
+        assert_eq!(amount, 100);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(3);
+        event_assert.assert_set_default_royalty(&default_receiver, 500);
+        event_assert.assert_set_token_royalty(&token_receiver, 7, 1_000);
+        event_assert.assert_remove_token_royalty(7);
+    });
+}
+
+#[test]
+fn royalty_amount_rounds_down() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &receiver, 250);
+
+        let (_, amount) = Base::royalty_info(&e, 1, 399);
+        assert_eq!(amount, 9);
+
+        let (_, amount) = Base::royalty_info(&e, 1, 0);
+        assert_eq!(amount, 0);
+    });
+}
+
+#[test]
+fn full_royalty_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_token_royalty(&e, 1, &receiver, 10_000);
+
+        let (_, amount) = Base::royalty_info(&e, 1, 12_345);
+        assert_eq!(amount, 12_345);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #315)")]
+fn set_default_royalty_above_denominator_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &receiver, 10_001);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #315)")]
+fn set_token_royalty_above_denominator_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_token_royalty(&e, 1, &receiver, 10_001);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #316)")]
+fn royalty_info_without_royalty_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::royalty_info(&e, 1, 1_000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #317)")]
+fn royalty_info_with_negative_sale_price_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &receiver, 500);
+        Base::royalty_info(&e, 1, -1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #305)")]
+fn royalty_info_overflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let receiver = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_default_royalty(&e, &receiver, 500);
+        Base::royalty_info(&e, 1, i128::MAX);
+    });
+}
@@ -0,0 +1,163 @@
+//! # Safe Transfer Extension for Non-Fungible Token
+//!
+//! The `safe_transfer` module mirrors the `safeTransferFrom` mechanism of
+//! ERC-721. When the recipient of a safe transfer is a contract, the token
+//! contract invokes [`NonFungibleReceiver::on_nft_received()`] on it, and the
+//! transfer is reverted unless the recipient acknowledges the token by
+//! returning the symbol [`ON_NFT_RECEIVED`]. This prevents NFTs from being
+//! locked forever in contracts that are unable to handle them.
+//!
+//! Transfers to accounts (`G...` addresses) behave exactly like regular
+//! transfers.
+//!
+//! ## Implementing a Receiver
+//!
+//! ```ignore
+//! #[contractimpl]
+//! impl NonFungibleReceiver for MyVault {
+//!     fn on_nft_received(
+//!         e: Env,
+//!         operator: Address,
+//!         from: Address,
+//!         token_id: TokenId,
+//!         data: Bytes,
+//!     ) -> Symbol {
+//!         /* bookkeeping */
+//!         Symbol::new(&e, ON_NFT_RECEIVED)
+//!     }
+//! }
+//! ```
+//!
+//! ## Notes
+//!
+//! - The callback is invoked after the token is transferred, so the receiver
+//!   already owns the token when `on_nft_received` runs.
+//! - Soroban does not allow re-entrancy, so the receiver cannot call back into
+//!   the token contract from `on_nft_received`.
+pub mod storage;
+
+mod test;
+
+use soroban_sdk::{contractclient, Address, Bytes, Env, Symbol};
+
+use crate::{NonFungibleToken, TokenId};
+
+/// The symbol a [`NonFungibleReceiver`] must return to accept a token.
+pub const ON_NFT_RECEIVED: &str = "on_nft_received";
+
+/// Receiver Trait for Non-Fungible Token
+///
+/// The `NonFungibleReceiver` trait has to be implemented by the contracts
+/// willing to receive non-fungible tokens through a safe transfer.
+#[contractclient(name = "NonFungibleReceiverClient")]
+pub trait NonFungibleReceiver {
+    /// Handles the receipt of a non-fungible token. Must return
+    /// `Symbol::new(&e, ON_NFT_RECEIVED)` to accept the token, otherwise the
+    /// transfer is reverted.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address which initiated the transfer.
+    /// * `from` - The previous owner of the token.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data with no specified format, forwarded from
+    ///   the safe transfer call.
+    fn on_nft_received(
+        e: Env,
+        operator: Address,
+        from: Address,
+        token_id: TokenId,
+        data: Bytes,
+    ) -> Symbol;
+}
+
+/// Safe Transfer Trait for Non-Fungible Token
+///
+/// The `NonFungibleSafeTransfer` trait extends the `NonFungibleToken` trait to
+/// provide transfers that check whether the recipient is able to receive the
+/// token. This trait is designed to be used in conjunction with the
+/// `NonFungibleToken` trait, and is compatible with every contract type.
+///
+/// # Notes
+///
+/// `#[contractimpl]` macro requires even the default implementations to be
+/// present under its scope. To not confuse the developers, we did not provide
+/// the default implementations here, but we are providing a macro to generate
+/// the default implementations for you.
+///
+/// When implementing [`NonFungibleSafeTransfer`] trait for your Smart
+/// Contract, you can follow the below example:
+///
+/// ```ignore
+/// #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+/// #[contractimpl]
+/// impl NonFungibleSafeTransfer for MyContract {
+///     /* your overrides here (you don't have to put anything here if you don't want to override anything) */
+///     /* and the macro will generate all the missing default implementations for you */
+/// }
+/// ```
+pub trait NonFungibleSafeTransfer: NonFungibleToken {
+    /// Transfers `token_id` token from `from` to `to`. If `to` is a contract,
+    /// it must implement [`NonFungibleReceiver`] and acknowledge the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::IncorrectOwner`] - If the current
+    ///   owner (before calling this function) is not `from`.
+    /// * [`crate::NonFungibleTokenError::NonExistentToken`] - If the token
+    ///   does not exist.
+    /// * [`crate::NonFungibleTokenError::InvalidReceiver`] - If `to` is a
+    ///   contract that does not acknowledge the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    fn safe_transfer(e: &Env, from: Address, to: Address, token_id: TokenId, data: Bytes);
+
+    /// Transfers `token_id` token from `from` to `to` by using `spender`s
+    /// approval. If `to` is a contract, it must implement
+    /// [`NonFungibleReceiver`] and acknowledge the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address authorizing the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`crate::NonFungibleTokenError::IncorrectOwner`] - If the current
+    ///   owner (before calling this function) is not `from`.
+    /// * [`crate::NonFungibleTokenError::InsufficientApproval`] - If the
+    ///   spender does not have a valid approval.
+    /// * [`crate::NonFungibleTokenError::NonExistentToken`] - If the token
+    ///   does not exist.
+    /// * [`crate::NonFungibleTokenError::InvalidReceiver`] - If `to` is a
+    ///   contract that does not acknowledge the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    fn safe_transfer_from(
+        e: &Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: TokenId,
+        data: Bytes,
+    );
+}
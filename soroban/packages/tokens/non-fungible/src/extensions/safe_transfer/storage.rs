@@ -0,0 +1,274 @@
+use soroban_sdk::{panic_with_error, xdr::ToXdr, Address, Bytes, Env, Symbol};
+
+use crate::{
+    consecutive::Consecutive,
+    enumerable::Enumerable,
+    safe_transfer::{NonFungibleReceiverClient, ON_NFT_RECEIVED},
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Offset of the `ScAddressType` in the XDR encoding of an address value,
+/// which starts with the 4 bytes `ScValType` followed by the 4 bytes
+/// `ScAddressType` (big-endian).
+const ADDRESS_TYPE_XDR_OFFSET: u32 = 7;
+
+/// `ScAddressType` of contract addresses (`0` is used for accounts).
+const SC_ADDRESS_TYPE_CONTRACT: u8 = 1;
+
+impl Base {
+    /// Transfers a non-fungible token (NFT), ensuring ownership checks, and
+    /// checks that `to` can receive it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::transfer`] errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn safe_transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId, data: &Bytes) {
+        Base::transfer(e, from, to, token_id);
+        Base::check_on_nft_received(e, from, from, to, token_id, data);
+    }
+
+    /// Transfers a non-fungible token (NFT), ensuring ownership and approval
+    /// checks, and checks that `to` can receive it.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Base::transfer_from`] errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn safe_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: &Bytes,
+    ) {
+        Base::transfer_from(e, spender, from, to, token_id);
+        Base::check_on_nft_received(e, spender, from, to, token_id, data);
+    }
+
+    // ################## LOW-LEVEL HELPERS ##################
+
+    /// Low-level function for checking that `to` is able to receive
+    /// `token_id`. If `to` is a contract, invokes
+    /// [`crate::safe_transfer::NonFungibleReceiver::on_nft_received`] on it
+    /// and requires the [`ON_NFT_RECEIVED`] acknowledgement. Does nothing if
+    /// `to` is not a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `operator` - The address which initiated the transfer.
+    /// * `from` - The previous owner of the token.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the transferred token.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidReceiver`] - If `to` is a contract
+    ///   that does not implement the receiver interface, fails, or returns a
+    ///   wrong acknowledgement.
+    pub fn check_on_nft_received(
+        e: &Env,
+        operator: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: &Bytes,
+    ) {
+        if !is_contract(e, to) {
+            return;
+        }
+
+        let client = NonFungibleReceiverClient::new(e, to);
+        let acknowledged = match client.try_on_nft_received(operator, from, &token_id, data) {
+            Ok(Ok(symbol)) => symbol == Symbol::new(e, ON_NFT_RECEIVED),
+            _ => false,
+        };
+
+        if !acknowledged {
+            panic_with_error!(e, NonFungibleTokenError::InvalidReceiver);
+        }
+    }
+}
+
+impl Enumerable {
+    /// Same as [`Base::safe_transfer`], but for the `Enumerable` contract
+    /// type.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Enumerable::transfer`] errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn safe_transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId, data: &Bytes) {
+        Enumerable::transfer(e, from, to, token_id);
+        Base::check_on_nft_received(e, from, from, to, token_id, data);
+    }
+
+    /// Same as [`Base::safe_transfer_from`], but for the `Enumerable`
+    /// contract type.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Enumerable::transfer_from`] errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn safe_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: &Bytes,
+    ) {
+        Enumerable::transfer_from(e, spender, from, to, token_id);
+        Base::check_on_nft_received(e, spender, from, to, token_id, data);
+    }
+}
+
+impl Consecutive {
+    /// Same as [`Base::safe_transfer`], but for the `Consecutive` contract
+    /// type.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::transfer`] errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `from` is required.
+    pub fn safe_transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId, data: &Bytes) {
+        Consecutive::transfer(e, from, to, token_id);
+        Base::check_on_nft_received(e, from, from, to, token_id, data);
+    }
+
+    /// Same as [`Base::safe_transfer_from`], but for the `Consecutive`
+    /// contract type.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `spender` - The address attempting to transfer the token.
+    /// * `from` - The current owner's address.
+    /// * `to` - The recipient's address.
+    /// * `token_id` - The identifier of the token being transferred.
+    /// * `data` - Additional data forwarded to the recipient contract.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`Consecutive::transfer_from`] errors.
+    /// * refer to [`Base::check_on_nft_received`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["transfer", from: Address, to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `spender` is required.
+    pub fn safe_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: &Bytes,
+    ) {
+        Consecutive::transfer_from(e, spender, from, to, token_id);
+        Base::check_on_nft_received(e, spender, from, to, token_id, data);
+    }
+}
+
+/// Returns `true` if `address` is a contract address (`C...`), and `false`
+/// if it is an account address (`G...`).
+///
+/// The address type is read from the XDR encoding of the address, which is
+/// cheaper than encoding it as a strkey.
+fn is_contract(e: &Env, address: &Address) -> bool {
+    address.clone().to_xdr(e).get(ADDRESS_TYPE_XDR_OFFSET) == Some(SC_ADDRESS_TYPE_CONTRACT)
+}
@@ -0,0 +1,259 @@
+#![cfg(test)]
+
+extern crate std;
+
+use receiver::ReceiverContract;
+use soroban_sdk::{contract, symbol_short, testutils::Address as _, Address, Bytes, Env};
+use stellar_event_assertion::EventAssertion;
+
+use wrong_ack::WrongAckReceiverContract;
+
+use crate::{consecutive::Consecutive, enumerable::Enumerable, Base, ContractOverrides, TokenId};
+
+#[contract]
+struct MockContract;
+
+mod receiver {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, Env, Symbol};
+
+    use crate::{
+        safe_transfer::{NonFungibleReceiver, ON_NFT_RECEIVED},
+        TokenId,
+    };
+
+    #[contract]
+    pub struct ReceiverContract;
+
+    #[contractimpl]
+    impl NonFungibleReceiver for ReceiverContract {
+        fn on_nft_received(
+            e: Env,
+            operator: Address,
+            from: Address,
+            token_id: TokenId,
+            data: Bytes,
+        ) -> Symbol {
+            e.storage()
+                .instance()
+                .set(&symbol_short!("RECEIVED"), &(operator, from, token_id, data));
+            Symbol::new(&e, ON_NFT_RECEIVED)
+        }
+    }
+}
+
+mod wrong_ack {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, Env, Symbol};
+
+    use crate::{safe_transfer::NonFungibleReceiver, TokenId};
+
+    #[contract]
+    pub struct WrongAckReceiverContract;
+
+    #[contractimpl]
+    impl NonFungibleReceiver for WrongAckReceiverContract {
+        fn on_nft_received(
+            _e: Env,
+            _operator: Address,
+            _from: Address,
+            _token_id: TokenId,
+            _data: Bytes,
+        ) -> Symbol {
+            symbol_short!("nope")
+        }
+    }
+}
+
+#[contract]
+struct NonReceiverContract;
+
+/// Returns an account address (`G...`): `Address::generate` only generates
+/// contract addresses.
+fn account(e: &Env) -> Address {
+    Address::from_str(e, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF")
+}
+
+fn received(e: &Env, receiver: &Address) -> Option<(Address, Address, TokenId, Bytes)> {
+    e.as_contract(receiver, || e.storage().instance().get(&symbol_short!("RECEIVED")))
+}
+
+#[test]
+fn safe_transfer_to_account_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = account(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &recipient, token_id, &Bytes::new(&e));
+
+        assert_eq!(Base::owner_of(&e, token_id), recipient);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_non_fungible_mint(&owner, token_id);
+        event_assert.assert_non_fungible_transfer(&owner, &recipient, token_id);
+    });
+}
+
+#[test]
+fn safe_transfer_to_receiver_contract_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(ReceiverContract, ());
+    let owner = Address::generate(&e);
+    let data = Bytes::from_slice(&e, b"hello");
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &receiver, token_id, &data);
+
+        assert_eq!(Base::owner_of(&e, token_id), receiver);
+        token_id
+    });
+
+    assert_eq!(received(&e, &receiver), Some((owner.clone(), owner, token_id, data)));
+}
+
+#[test]
+fn safe_transfer_from_to_receiver_contract_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(ReceiverContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    let token_id = e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::approve(&e, &owner, &spender, token_id, 1000);
+        token_id
+    });
+
+    e.as_contract(&address, || {
+        Base::safe_transfer_from(&e, &spender, &owner, &receiver, token_id, &Bytes::new(&e));
+
+        assert_eq!(Base::owner_of(&e, token_id), receiver);
+    });
+
+    // the operator is the spender
+    assert_eq!(received(&e, &receiver), Some((spender, owner, token_id, Bytes::new(&e))));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn safe_transfer_to_wrong_ack_contract_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(WrongAckReceiverContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &receiver, token_id, &Bytes::new(&e));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn safe_transfer_to_non_receiver_contract_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(NonReceiverContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::safe_transfer(&e, &owner, &receiver, token_id, &Bytes::new(&e));
+    });
+}
+
+#[test]
+fn enumerable_safe_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(ReceiverContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Enumerable::sequential_mint(&e, &owner);
+        Enumerable::safe_transfer(&e, &owner, &receiver, token_id, &Bytes::new(&e));
+
+        assert_eq!(Base::owner_of(&e, token_id), receiver);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &receiver, 0), token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn enumerable_safe_transfer_from_to_non_receiver_contract_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(NonReceiverContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Enumerable::sequential_mint(&e, &owner);
+        Enumerable::safe_transfer_from(&e, &owner, &owner, &receiver, token_id, &Bytes::new(&e));
+    });
+}
+
+#[test]
+fn consecutive_safe_transfer_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(ReceiverContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+        Consecutive::safe_transfer(&e, &owner, &receiver, 5, &Bytes::new(&e));
+
+        assert_eq!(Consecutive::owner_of(&e, 5), receiver);
+        assert_eq!(Consecutive::owner_of(&e, 6), owner);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn consecutive_safe_transfer_to_wrong_ack_contract_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(WrongAckReceiverContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+        Consecutive::safe_transfer_from(&e, &owner, &owner, &receiver, 5, &Bytes::new(&e));
+    });
+}
+
+#[test]
+fn contract_overrides_safe_transfer_uses_contract_type() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let receiver = e.register(ReceiverContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Consecutive::batch_mint(&e, &owner, 10);
+        <Consecutive as ContractOverrides>::safe_transfer(
+            &e,
+            &owner,
+            &receiver,
+            5,
+            &Bytes::new(&e),
+        );
+
+        assert_eq!(Consecutive::owner_of(&e, 5), receiver);
+    });
+}
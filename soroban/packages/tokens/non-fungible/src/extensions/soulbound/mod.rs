@@ -0,0 +1,54 @@
+//! # Soulbound Extension for Non-Fungible Token
+//!
+//! The `soulbound` module provides a contract type for non-transferable
+//! tokens, which are bound to the account they are minted to. This is useful
+//! for credentials, certificates, memberships and alike.
+//!
+//! ## Implementation Notes
+//!
+//! - **Minting**: Tokens are minted with the functions of [`crate::Base`]
+//!   (e.g. [`crate::Base::sequential_mint`]).
+//! - **Transfer & Approval**: `transfer`, `transfer_from`, `batch_transfer`,
+//!   `batch_transfer_from`, `approve` and `approve_for_all` always panic with
+//!   [`crate::NonFungibleTokenError::NonTransferableToken`].
+//! - **Burn**: Burning is still possible with [`crate::Base::burn`], if the
+//!   contract decides to expose it, e.g. through
+//!   [`crate::burnable::NonFungibleBurnable`].
+//!
+//! ## Usage
+//!
+//! Set [`Soulbound`] as the `ContractType` of the `NonFungibleToken`
+//! implementation, and `#[default_impl]` will route the transfer and approval
+//! methods to the soulbound overrides:
+//!
+//! ```ignore
+//! #[default_impl] // **IMPORTANT**: place this above `#[contractimpl]`
+//! #[contractimpl]
+//! impl NonFungibleToken for MyContract {
+//!     type ContractType = Soulbound;
+//! }
+//! ```
+//!
+//! A soulbound contract willing to allow burning can implement
+//! [`crate::burnable::NonFungibleBurnable`] as well:
+//!
+//! ```ignore
+//! #[default_impl]
+//! #[contractimpl]
+//! impl NonFungibleBurnable for MyContract {}
+//! ```
+pub mod storage;
+pub use storage::Soulbound;
+
+use crate::NonFungibleToken;
+
+/// Soulbound Marker Trait for Non-Fungible Token
+///
+/// # Notes
+///
+/// The `soulbound` extension disables transfers and approvals. Therefore,
+/// this trait is INCOMPATIBLE with the `Consecutive` and `Enumerable`
+/// extensions, which provide their own contract types.
+pub trait NonFungibleSoulbound: NonFungibleToken<ContractType = Soulbound> {}
+
+mod test;
@@ -0,0 +1,142 @@
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+use crate::{ContractOverrides, NonFungibleTokenError, TokenId};
+
+pub struct Soulbound;
+
+impl ContractOverrides for Soulbound {
+    fn transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId) {
+        Soulbound::transfer(e, from, to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: &Address, from: &Address, to: &Address, token_id: TokenId) {
+        Soulbound::transfer_from(e, spender, from, to, token_id);
+    }
+
+    fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        Soulbound::batch_transfer(e, from, to, token_ids);
+    }
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        Soulbound::batch_transfer_from(e, spender, from, to, token_ids);
+    }
+
+    fn batch_approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_ids: &Vec<TokenId>,
+        live_until_ledger: u32,
+    ) {
+        Soulbound::batch_approve(e, approver, approved, token_ids, live_until_ledger);
+    }
+
+    fn approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_id: TokenId,
+        live_until_ledger: u32,
+    ) {
+        Soulbound::approve(e, approver, approved, token_id, live_until_ledger);
+    }
+
+    fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
+        Soulbound::approve_for_all(e, owner, operator, live_until_ledger);
+    }
+}
+
+impl Soulbound {
+    /// Soulbound tokens cannot be transferred, this function always panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `_from` - Account of the sender.
+    /// * `_to` - Account of the recipient.
+    /// * `_token_id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonTransferableToken`] - Always.
+    pub fn transfer(e: &Env, _from: &Address, _to: &Address, _token_id: TokenId) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferableToken);
+    }
+
+    /// Soulbound tokens cannot be transferred, this function always panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `_spender` - The address authorizing the transfer.
+    /// * `_from` - Account of the sender.
+    /// * `_to` - Account of the recipient.
+    /// * `_token_id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonTransferableToken`] - Always.
+    pub fn transfer_from(
+        e: &Env,
+        _spender: &Address,
+        _from: &Address,
+        _to: &Address,
+        _token_id: TokenId,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferableToken);
+    }
+
+    /// Soulbound tokens cannot be approved, since they cannot be transferred.
+    /// This function always panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `_approver` - The address of the approver.
+    /// * `_approved` - The address receiving the approval.
+    /// * `_token_id` - Token id as a number.
+    /// * `_live_until_ledger` - The ledger number at which the allowance
+    ///   expires.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonTransferableToken`] - Always.
+    pub fn approve(
+        e: &Env,
+        _approver: &Address,
+        _approved: &Address,
+        _token_id: TokenId,
+        _live_until_ledger: u32,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferableToken);
+    }
+
+    /// Soulbound tokens cannot be approved, since they cannot be transferred.
+    /// This function always panics.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `_owner` - The address holding the tokens.
+    /// * `_operator` - Account to add to the set of authorized operators.
+    /// * `_live_until_ledger` - The ledger number at which the allowance
+    ///   expires.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::NonTransferableToken`] - Always.
+    pub fn approve_for_all(
+        e: &Env,
+        _owner: &Address,
+        _operator: &Address,
+        _live_until_ledger: u32,
+    ) {
+        panic_with_error!(e, NonFungibleTokenError::NonTransferableToken);
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+use crate::{soulbound::Soulbound, Base, ContractOverrides};
+
+#[contract]
+struct MockContract;
+
+#[test]
+fn soulbound_mint_and_burn_work() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        assert_eq!(<Soulbound as ContractOverrides>::owner_of(&e, token_id), owner);
+        assert_eq!(<Soulbound as ContractOverrides>::balance(&e, &owner), 1);
+
+        Base::burn(&e, &owner, token_id);
+
+        assert_eq!(<Soulbound as ContractOverrides>::balance(&e, &owner), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn soulbound_transfer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        <Soulbound as ContractOverrides>::transfer(&e, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn soulbound_transfer_from_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        <Soulbound as ContractOverrides>::transfer_from(&e, &spender, &owner, &recipient, token_id);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn soulbound_approve_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        <Soulbound as ContractOverrides>::approve(&e, &owner, &approved, token_id, 1000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")]
+fn soulbound_approve_for_all_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        <Soulbound as ContractOverrides>::approve_for_all(&e, &owner, &operator, 1000);
+    });
+}
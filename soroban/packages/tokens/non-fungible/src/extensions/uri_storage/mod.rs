@@ -0,0 +1,25 @@
+//! # URI Storage Extension for Non-Fungible Token
+//!
+//! By default, the URI of a token is composed from the collection base URI and
+//! the `token_id` (`base_uri/<token_id>`). The `uri_storage` module lets a
+//! contract store a dedicated URI per token instead, which is useful for
+//! collections where every token points to its own content identifier (e.g.
+//! an IPFS CID).
+//!
+//! ## Implementation Notes
+//!
+//! - **token_uri**: [`crate::Base::token_uri`] and
+//!   [`crate::consecutive::Consecutive::token_uri`] return the stored URI if
+//!   there is one, and fall back to the composed base URI otherwise.
+//! - **Burn**: The stored URI is cleared when a token is burned, by both
+//!   [`crate::Base::update`] and [`crate::consecutive::Consecutive::update`].
+//! - **Events**: Setting or removing a stored URI emits a `metadata_update`
+//!   event, so that indexers can refresh the token metadata.
+//!
+//! Unlike other extensions, the `uri_storage` extension does not provide a
+//! separate trait, since [`crate::NonFungibleToken::token_uri`] already
+//! exposes the stored URIs.
+pub mod storage;
+pub use storage::StorageKey;
+
+mod test;
@@ -0,0 +1,97 @@
+use soroban_sdk::{contracttype, panic_with_error, Env, String};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{
+    non_fungible::{emit_metadata_update, MAX_TOKEN_URI_LEN},
+    Base, NonFungibleTokenError, TokenId,
+};
+
+/// Storage keys for the data associated with the `uri_storage` extension
+#[contracttype]
+pub enum StorageKey {
+    TokenUri(TokenId),
+}
+
+// `URI Storage` extension is compatible with every contract type, the stored
+// URIs are picked up by both `Base::token_uri` and `Consecutive::token_uri`.
+impl Base {
+    // ################## QUERY STATE ##################
+
+    /// Returns the URI stored for `token_id`, or `None` if there is none.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Notes
+    ///
+    /// This function does not check whether `token_id` exists.
+    pub fn stored_token_uri(e: &Env, token_id: TokenId) -> Option<String> {
+        let key = StorageKey::TokenUri(token_id);
+        e.storage().persistent().get::<_, String>(&key).inspect(|_| {
+            e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        })
+    }
+
+    // ################## CHANGE STATE ##################
+
+    /// Stores a dedicated URI for `token_id`, which is returned by `token_uri`
+    /// instead of the composed base URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    /// * `uri` - The URI of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::TokenUriMaxLenExceeded`] - If the length of
+    ///   `uri` exceeds the maximum allowed.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update"]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls, and does not
+    /// check whether `token_id` exists. You want to invoke it most likely
+    /// right after minting, or from another function with admin-only
+    /// authorization.
+    pub fn set_token_uri(e: &Env, token_id: TokenId, uri: String) {
+        if uri.len() as usize > MAX_TOKEN_URI_LEN {
+            panic_with_error!(e, NonFungibleTokenError::TokenUriMaxLenExceeded);
+        }
+
+        e.storage().persistent().set(&StorageKey::TokenUri(token_id), &uri);
+
+        emit_metadata_update(e, token_id);
+    }
+
+    /// Removes the URI stored for `token_id`, so that `token_uri` falls back
+    /// to the composed base URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["metadata_update"]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it most likely from another function with admin-only
+    /// authorization.
+    pub fn remove_token_uri(e: &Env, token_id: TokenId) {
+        e.storage().persistent().remove(&StorageKey::TokenUri(token_id));
+
+        emit_metadata_update(e, token_id);
+    }
+}
@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env, String};
+use stellar_event_assertion::EventAssertion;
+
+use crate::{consecutive::Consecutive, Base};
+
+#[contract]
+struct MockContract;
+
+fn set_metadata(e: &Env) {
+    Base::set_metadata(
+        e,
+        String::from_str(e, "https://smth.com/"),
+        String::from_str(e, "My NFT collection"),
+        String::from_str(e, "NFT"),
+    );
+}
+
+#[test]
+fn stored_token_uri_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        let token_id = Base::sequential_mint(&e, &owner);
+        let uri = String::from_str(&e, "ipfs://bafkreigjf3tymofuq5vepmlijsglf65qprsiwykkkz6i");
+
+        Base::set_token_uri(&e, token_id, uri.clone());
+
+        assert_eq!(Base::stored_token_uri(&e, token_id), Some(uri.clone()));
+        assert_eq!(Base::token_uri(&e, token_id), uri);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_non_fungible_mint(&owner, token_id);
+        event_assert.assert_metadata_update(token_id);
+    });
+}
+
+#[test]
+fn token_uri_falls_back_to_base_uri() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        let token_id = Base::sequential_mint(&e, &owner);
+        let other_id = Base::sequential_mint(&e, &owner);
+
+        Base::set_token_uri(&e, token_id, String::from_str(&e, "ipfs://cid"));
+
+        assert_eq!(Base::stored_token_uri(&e, other_id), None);
+        assert_eq!(Base::token_uri(&e, other_id), String::from_str(&e, "https://smth.com/1"));
+    });
+}
+
+#[test]
+fn remove_token_uri_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        let _ = Base::sequential_mint(&e, &owner);
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        Base::set_token_uri(&e, token_id, String::from_str(&e, "ipfs://cid"));
+        Base::remove_token_uri(&e, token_id);
+
+        assert_eq!(Base::stored_token_uri(&e, token_id), None);
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, "https://smth.com/1"));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(4);
+        event_assert.assert_metadata_update(token_id);
+        event_assert.assert_metadata_update(token_id);
+    });
+}
+
+#[test]
+fn burn_clears_stored_token_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        Base::set_token_uri(&e, token_id, String::from_str(&e, "ipfs://cid"));
+
+        Base::burn(&e, &owner, token_id);
+
+        assert_eq!(Base::stored_token_uri(&e, token_id), None);
+    });
+}
+
+#[test]
+fn transfer_keeps_stored_token_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        let uri = String::from_str(&e, "ipfs://cid");
+        Base::set_token_uri(&e, token_id, uri.clone());
+
+        Base::transfer(&e, &owner, &recipient, token_id);
+
+        assert_eq!(Base::token_uri(&e, token_id), uri);
+    });
+}
+
+#[test]
+fn consecutive_stored_token_uri_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_metadata(&e);
+        Consecutive::batch_mint(&e, &owner, 10);
+        let uri = String::from_str(&e, "ipfs://cid");
+
+        Base::set_token_uri(&e, 5, uri.clone());
+
+        assert_eq!(Consecutive::token_uri(&e, 5), uri);
+        assert_eq!(Consecutive::token_uri(&e, 6), String::from_str(&e, "https://smth.com/6"));
+
+        Consecutive::burn(&e, &owner, 5);
+
+        assert_eq!(Base::stored_token_uri(&e, 5), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #300)")]
+fn token_uri_of_non_existent_token_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_token_uri(&e, 1, String::from_str(&e, "ipfs://cid"));
+
+        Base::token_uri(&e, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #318)")]
+fn set_token_uri_too_long_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let token_id = Base::sequential_mint(&e, &owner);
+        let uri = String::from_str(&e, &"a".repeat(201));
+
+        Base::set_token_uri(&e, token_id, uri);
+    });
+}
@@ -34,11 +34,34 @@
 //!
 //! The following optional extensions are available:
 //!
+//! - *Allowlist* restricts minting to the accounts of a Merkle tree, each
+//!   with its own allowance.
+//! - *Approvals* lists the operators of an account and the approval of a
+//!   token, alongside their expiration ledgers.
+//! - *Batch Transfer* moves several tokens to the same recipient, or approves
+//!   an address for several tokens, in a single call.
 //! - *Burnable* enables token holders to destroy their non-fungible tokens.
+//! - *Capped* enables the contract to set a maximum limit on the number of
+//!   tokens that can be minted.
 //! - *Enumerable* allows for enumeration of all the token IDs in the contract
 //!   as well as all the token IDs owned by each account.
 //! - *Consecutive* is useful for efficiently minting multiple tokens in a
 //!   single transaction.
+//! - *Enumerable Consecutive* combines the batch minting of *Consecutive*
+//!   with the enumeration of *Enumerable*.
+//! - *Rentable* allows granting the `user` role of a token for a limited
+//!   time without transferring it, in the spirit of ERC-4907.
+//! - *Reveal* returns a placeholder URI until the collection is revealed,
+//!   optionally committed to with a provenance hash and shuffled with a
+//!   random offset.
+//! - *Royalties* signals royalty payment information to marketplaces, in the
+//!   spirit of ERC-2981.
+//! - *Safe Transfer* checks that the recipient contract is able to receive
+//!   the token, in the spirit of ERC-721's `safeTransferFrom`.
+//! - *Soulbound* makes tokens non-transferable, binding them to the account
+//!   they are minted to.
+//! - *URI Storage* allows storing a dedicated URI per token, instead of
+//!   composing it from the base URI.
 //!
 //! ## Compatibility and Compliance
 //!
@@ -49,8 +72,8 @@
 //!   Token interface, and also it is a simpler (thus, cheaper and faster)
 //!   version of `transferFrom()`, which may become handy depending on the
 //!   context.
-//! - `safeTransfer` mechanism is not present in the base module, (will be
-//!   provided as an extension)
+//! - `safeTransfer` mechanism is not present in the base module, it is
+//!   provided by the *Safe Transfer* extension instead.
 //! - `name()`, `symbol()` and `token_uri()` functionalities are made available
 //!   to be consistent with fungible tokens as well.
 //!
@@ -63,6 +86,12 @@
 //! - **Composable Design**: The modular structure encourages developers to
 //!   extend functionality by combining provided primitives or creating custom
 //!   extensions.
+//! - **Token URI Format**: The format of the composed token URIs (see
+//!   [`UriFormat`]) is not part of the stored `Metadata`, but is stored under
+//!   its own key. Adding a field to `Metadata` would make the metadata stored
+//!   by a contract deployed before the format was introduced undecodable
+//!   after an upgrade. When no format is stored, the token URIs are composed
+//!   as `{base_uri}/{token_id}`.
 #![no_std]
 
 mod extensions;
@@ -71,13 +100,17 @@ mod overrides;
 mod storage;
 mod utils;
 
-pub use extensions::{burnable, consecutive, enumerable};
+pub use extensions::{
+    allowlist, approvals, batch_transfer, burnable, capped, consecutive, enumerable,
+    enumerable_consecutive, rentable, reveal, royalties, safe_transfer, soulbound, uri_storage,
+};
 pub use non_fungible::{
-    emit_approve, emit_approve_for_all, emit_transfer, Balance, NonFungibleToken,
-    NonFungibleTokenError, TokenId,
+    emit_approve, emit_approve_for_all, emit_batch_metadata_update, emit_metadata_update,
+    emit_transfer, Balance, NonFungibleToken, NonFungibleTokenError, TokenId, MAX_BASE_URI_LEN,
+    MAX_BATCH_MINT_RECIPIENTS, MAX_URI_SEPARATOR_LEN, MAX_URI_SUFFIX_LEN,
 };
 pub use overrides::*;
-pub use storage::{ApprovalData, ApprovalForAllData, StorageKey};
-pub use utils::sequential;
+pub use storage::{ApprovalData, ApprovalForAllData, IdEncoding, StorageKey, UriFormat};
+pub use utils::{random_ids, sequential};
 
 mod test;
@@ -5,6 +5,22 @@ use crate::ContractOverrides;
 /// Max. allowed length for a base uri.
 pub const MAX_BASE_URI_LEN: usize = 200;
 
+/// Max. allowed length for a uri stored for a single token.
+pub const MAX_TOKEN_URI_LEN: usize = 200;
+
+/// Max. allowed length for the separator put between the base uri and the
+/// token id.
+pub const MAX_URI_SEPARATOR_LEN: usize = 8;
+
+/// Max. allowed length for the suffix appended to the token id.
+pub const MAX_URI_SUFFIX_LEN: usize = 16;
+
+/// Max. number of recipients of a single call to
+/// [`crate::Base::batch_mint_to`] or `batch_mint_to_many` (see
+/// [`crate::consecutive`]), keeping it within the resource limits of a
+/// transaction.
+pub const MAX_BATCH_MINT_RECIPIENTS: u32 = 100;
+
 #[cfg(feature = "token_u32")]
 pub type TokenId = u32;
 /// u32::MAX == 4294967295
@@ -19,7 +35,7 @@ pub const MAX_NUM_DIGITS: usize = 20;
 
 #[cfg(feature = "token_u128")]
 pub type TokenId = u128;
-/// u128::MAX == 18446744073709551615
+/// u128::MAX == 340282366920938463463374607431768211455
 #[cfg(feature = "token_u128")]
 pub const MAX_NUM_DIGITS: usize = 39;
 
@@ -61,21 +77,31 @@ pub type Balance = TokenId;
 /// ```
 ///
 /// This trait is implemented for the following Contract Types:
-/// * [`crate::Base`] (covering the vanilla case, and compatible with
-///   [`crate::extensions::burnable::NonFungibleBurnable`]) trait
+/// * [`crate::Base`] (covering the vanilla case).
 /// * [`crate::extensions::enumerable::Enumerable`] (enabling the compatibility
 ///   and overrides for
 ///   [`crate::extensions::enumerable::NonFungibleEnumerable`]) trait,
-///   incompatible with [`crate::extensions::burnable::NonFungibleBurnable`])
-///   and [`crate::extensions::consecutive::NonFungibleConsecutive`] trait.
+///   incompatible with
+///   [`crate::extensions::consecutive::NonFungibleConsecutive`] trait.
 /// * [`crate::extensions::consecutive::Consecutive`] (enabling the
 ///   compatibility and overrides for
 ///   [`crate::extensions::consecutive::NonFungibleConsecutive`]) trait,
-///   incompatible with [`crate::extensions::burnable::NonFungibleBurnable`])
-///   and [`crate::extensions::enumerable::NonFungibleEnumerable`] trait.
+///   incompatible with
+///   [`crate::extensions::enumerable::NonFungibleEnumerable`] trait.
+/// * [`crate::extensions::enumerable_consecutive::EnumerableConsecutive`]
+///   (enabling the compatibility and overrides for
+///   [`crate::extensions::enumerable_consecutive::NonFungibleEnumerableConsecutive`])
+///   trait, which combines the two above.
+/// * [`crate::extensions::soulbound::Soulbound`] (enabling the compatibility
+///   and overrides for [`crate::extensions::soulbound::NonFungibleSoulbound`])
+///   trait, which disables transfers and approvals.
+///
+/// All of them are compatible with
+/// [`crate::extensions::burnable::NonFungibleBurnable`], which burns the
+/// tokens with the logic of the respective Contract Type.
 ///
 /// You can find the default implementations of this trait for `Base`,
-/// `Enumerable`, and `Consecutive`, by navigating to:
+/// `Enumerable`, `Consecutive`, and `Soulbound`, by navigating to:
 /// `ContractType::{method_name}`. For example, if you want to find how
 /// [`NonFungibleToken::transfer`] is implemented for the `Enumerable` Contract
 /// Type, you can find it using
@@ -112,7 +138,9 @@ pub trait NonFungibleToken {
     ///
     /// WARNING: Note that the caller is responsible to confirm that the
     /// recipient is capable of receiving the `Non-Fungible` or else the NFT
-    /// may be permanently lost.
+    /// may be permanently lost. See
+    /// [`crate::safe_transfer::NonFungibleSafeTransfer`] for transfers that
+    /// check the recipient.
     ///
     /// # Arguments
     ///
@@ -144,7 +172,9 @@ pub trait NonFungibleToken {
     ///
     /// WARNING: Note that the caller is responsible to confirm that the
     /// recipient is capable of receiving the `Non-Fungible` or else the NFT
-    /// may be permanently lost.
+    /// may be permanently lost. See
+    /// [`crate::safe_transfer::NonFungibleSafeTransfer`] for transfers that
+    /// check the recipient.
     ///
     /// # Arguments
     ///
@@ -313,6 +343,54 @@ pub enum NonFungibleTokenError {
     UnsetMetadata = 310,
     /// Indicates the length of the base URI exceeds the maximum allowed
     BaseUriMaxLenExceeded = 311,
+    /// Indicates the cap was not set.
+    CapNotSet = 312,
+    /// Indicates that the operation would have caused the number of minted
+    /// tokens to exceed the `cap`.
+    ExceededCap = 313,
+    /// Indicates the supplied `cap` is lower than the number of tokens already
+    /// minted.
+    InvalidCap = 314,
+    /// Indicates the royalty fraction exceeds the fee denominator.
+    InvalidRoyalty = 315,
+    /// Indicates neither a token-specific nor a default royalty is set.
+    RoyaltyNotSet = 316,
+    /// Indicates a negative sale price when querying royalties.
+    InvalidSalePrice = 317,
+    /// Indicates the length of a token URI exceeds the maximum allowed
+    TokenUriMaxLenExceeded = 318,
+    /// Indicates an attempt to transfer or approve a non-transferable
+    /// (soulbound) token.
+    NonTransferableToken = 319,
+    /// Indicates the recipient contract of a safe transfer did not
+    /// acknowledge the token.
+    InvalidReceiver = 320,
+    /// Indicates access to the allowlist root before it is set.
+    AllowlistNotSet = 321,
+    /// Indicates an allowlist proof that does not match the allowlist root.
+    InvalidAllowlistProof = 322,
+    /// Indicates an account attempts to mint more tokens than its allowlist
+    /// allowance.
+    AllowlistAllowanceExceeded = 323,
+    /// Indicates an attempt to change the reveal settings of a revealed
+    /// collection.
+    AlreadyRevealed = 324,
+    /// Indicates an attempt to overwrite the provenance hash.
+    ProvenanceAlreadySet = 325,
+    /// Indicates the revealed base URI does not match the provenance hash.
+    InvalidProvenance = 326,
+    /// Indicates an attempt to set the random `token_id` range twice.
+    InvalidTokenIdRange = 327,
+    /// Indicates a URI format with a too long separator or suffix, or a
+    /// padding width above the max. number of digits of `TokenId`.
+    InvalidUriFormat = 328,
+    /// Indicates a batch mint of zero tokens or of more tokens than the max.
+    /// batch size (or recipients than allowed), or an attempt to set the max.
+    /// batch size to zero.
+    InvalidBatchSize = 329,
+    /// Indicates batch checkpoints that are not strictly increasing, or that
+    /// refer to tokens not minted yet.
+    InvalidBatchCheckpoint = 330,
 }
 
 // ################## EVENTS ##################
@@ -399,3 +477,38 @@ pub fn emit_mint(e: &Env, to: &Address, token_id: TokenId) {
     let topics = (symbol_short!("mint"), to);
     e.events().publish(topics, token_id)
 }
+
+/// Emits an event indicating the metadata of a token has changed, so that
+/// third-party platforms such as marketplaces can refresh it.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `token_id` - The identifier of the token whose metadata changed.
+///
+/// # Events
+///
+/// * topics - `["metadata_update"]`
+/// * data - `[token_id: TokenId]`
+pub fn emit_metadata_update(e: &Env, token_id: TokenId) {
+    let topics = (Symbol::new(e, "metadata_update"),);
+    e.events().publish(topics, token_id)
+}
+
+/// Emits an event indicating the metadata of a range of tokens has changed, so
+/// that third-party platforms such as marketplaces can refresh it.
+///
+/// # Arguments
+///
+/// * `e` - Access to Soroban environment.
+/// * `from_token_id` - First token id in the range.
+/// * `to_token_id` - Last token id in the range.
+///
+/// # Events
+///
+/// * topics - `["batch_metadata_update"]`
+/// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+pub fn emit_batch_metadata_update(e: &Env, from_token_id: TokenId, to_token_id: TokenId) {
+    let topics = (Symbol::new(e, "batch_metadata_update"),);
+    e.events().publish(topics, (from_token_id, to_token_id))
+}
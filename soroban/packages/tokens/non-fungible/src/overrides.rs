@@ -1,6 +1,6 @@
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, Bytes, Env, String, Vec};
 
-use crate::{Balance, TokenId};
+use crate::{ApprovalData, Balance, TokenId};
 
 /// Based on the Extension, some default behavior of [`crate::NonFungibleToken`]
 /// might have to be overridden. This is a helper trait that allows us this
@@ -101,6 +101,55 @@ pub trait ContractOverrides {
         Base::transfer_from(e, spender, from, to, token_id);
     }
 
+    fn safe_transfer(e: &Env, from: &Address, to: &Address, token_id: TokenId, data: &Bytes) {
+        Self::transfer(e, from, to, token_id);
+        Base::check_on_nft_received(e, from, from, to, token_id, data);
+    }
+
+    fn safe_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_id: TokenId,
+        data: &Bytes,
+    ) {
+        Self::transfer_from(e, spender, from, to, token_id);
+        Base::check_on_nft_received(e, spender, from, to, token_id, data);
+    }
+
+    fn batch_transfer(e: &Env, from: &Address, to: &Address, token_ids: &Vec<TokenId>) {
+        Base::batch_transfer(e, from, to, token_ids);
+    }
+
+    fn batch_transfer_from(
+        e: &Env,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        token_ids: &Vec<TokenId>,
+    ) {
+        Base::batch_transfer_from(e, spender, from, to, token_ids);
+    }
+
+    fn batch_approve(
+        e: &Env,
+        approver: &Address,
+        approved: &Address,
+        token_ids: &Vec<TokenId>,
+        live_until_ledger: u32,
+    ) {
+        Base::batch_approve(e, approver, approved, token_ids, live_until_ledger);
+    }
+
+    fn burn(e: &Env, from: &Address, token_id: TokenId) {
+        Base::burn(e, from, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: TokenId) {
+        Base::burn_from(e, spender, from, token_id);
+    }
+
     fn approve(
         e: &Env,
         approver: &Address,
@@ -123,6 +172,22 @@ pub trait ContractOverrides {
         Base::is_approved_for_all(e, owner, operator)
     }
 
+    fn approval_of(e: &Env, token_id: TokenId) -> Option<ApprovalData> {
+        Base::approval_of(e, token_id)
+    }
+
+    fn revoke_approval(e: &Env, approver: &Address, token_id: TokenId) {
+        Base::revoke_approval(e, approver, token_id);
+    }
+
+    fn operators_of(e: &Env, owner: &Address) -> Vec<(Address, u32)> {
+        Base::operators_of(e, owner)
+    }
+
+    fn set_user(e: &Env, caller: &Address, token_id: TokenId, user: &Address, expires_ledger: u32) {
+        Base::set_user(e, caller, token_id, user, expires_ledger);
+    }
+
     fn name(e: &Env) -> String {
         Base::name(e)
     }
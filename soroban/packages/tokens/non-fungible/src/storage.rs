@@ -1,20 +1,24 @@
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, String};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, String, Vec};
 use stellar_constants::{
     BALANCE_EXTEND_AMOUNT, BALANCE_TTL_THRESHOLD, OWNER_EXTEND_AMOUNT, OWNER_TTL_THRESHOLD,
 };
 
 use crate::{
+    capped::record_mint,
     non_fungible::{
-        emit_approve, emit_approve_for_all, emit_mint, emit_transfer, Balance,
-        NonFungibleTokenError, TokenId, MAX_BASE_URI_LEN, MAX_NUM_DIGITS,
+        emit_approve, emit_approve_for_all, emit_batch_metadata_update, emit_mint, emit_transfer,
+        Balance, NonFungibleTokenError, TokenId, MAX_BASE_URI_LEN, MAX_BATCH_MINT_RECIPIENTS,
+        MAX_NUM_DIGITS, MAX_URI_SEPARATOR_LEN, MAX_URI_SUFFIX_LEN,
     },
-    sequential::increment_token_id,
-    Base,
+    random_ids::next_random_token_id,
+    sequential::{increment_token_id, next_token_id},
+    uri_storage, Base,
 };
 
 /// Storage container for the token for which an approval is granted
 /// and the ledger number at which this approval expires.
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ApprovalData {
     pub approved: Address,
     pub live_until_ledger: u32,
@@ -26,6 +30,46 @@ pub struct ApprovalForAllData {
     pub operators: Map<Address /* operator */, u32 /* live_until_ledger */>,
 }
 
+/// Encoding of the `token_id` in a composed token URI.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IdEncoding {
+    Decimal,
+    /// Lowercase hexadecimal, without `0x` prefix.
+    Hex,
+}
+
+/// Describes how a token URI is composed from the base URI, i.e.
+/// `{base_uri}{separator}{token_id}{suffix}`.
+///
+/// Stored under [`StorageKey::UriFormat`], apart from [`Metadata`], so that
+/// the `Metadata` of contracts upgraded from a previous version still decodes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UriFormat {
+    /// Put between the base URI and the `token_id`, unless the base URI
+    /// already ends with it.
+    pub separator: String,
+    /// Appended to the `token_id`, e.g. `.json`.
+    pub suffix: String,
+    pub encoding: IdEncoding,
+    /// Minimum number of digits of the `token_id`, left-padded with zeros.
+    pub width: u32,
+}
+
+impl UriFormat {
+    /// Returns the default format, i.e. `{base_uri}/{token_id}` with a
+    /// decimal `token_id`.
+    pub fn new(e: &Env) -> Self {
+        UriFormat {
+            separator: String::from_str(e, "/"),
+            suffix: String::from_str(e, ""),
+            encoding: IdEncoding::Decimal,
+            width: 0,
+        }
+    }
+}
+
 /// Storage container for token metadata
 #[contracttype]
 pub struct Metadata {
@@ -42,6 +86,7 @@ pub enum StorageKey {
     Approval(TokenId),
     ApprovalForAll(Address),
     Metadata,
+    UriFormat,
 }
 
 impl Base {
@@ -193,7 +238,22 @@ impl Base {
         Base::get_metadata(e).base_uri
     }
 
-    /// Returns the URI for a specific `token_id`.
+    /// Returns the format used to compose the token URIs. Defaults to
+    /// [`UriFormat::new`], i.e. `{base_uri}/{token_id}`, if no format is
+    /// stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    pub fn uri_format(e: &Env) -> UriFormat {
+        e.storage().instance().get(&StorageKey::UriFormat).unwrap_or_else(|| UriFormat::new(e))
+    }
+
+    /// Returns the URI for a specific `token_id`. Until the collection is
+    /// revealed, the placeholder URI is returned if there is one (see
+    /// [`crate::reveal`]). If a URI is stored for the token (see
+    /// [`crate::uri_storage`]), it is returned as is, otherwise the URI is
+    /// composed from the base URI.
     ///
     /// # Arguments
     ///
@@ -206,41 +266,97 @@ impl Base {
     /// * refer to [`base_uri`] errors.
     pub fn token_uri(e: &Env, token_id: TokenId) -> String {
         let _ = Base::owner_of(e, token_id);
+        if let Some(uri) = Base::placeholder_uri(e) {
+            return uri;
+        }
+        if let Some(uri) = Base::stored_token_uri(e, token_id) {
+            return uri;
+        }
         let base_uri = Base::base_uri(e);
-        Base::compose_uri_for_token(e, base_uri, token_id)
+        Base::compose_uri_with_format(
+            e,
+            base_uri,
+            Base::revealed_token_id(e, token_id),
+            &Base::uri_format(e),
+        )
     }
 
-    /// Composes and returns a the URI for a specific `token_id`, without
-    /// checking its ownership.
+    /// Composes and returns the URI for a specific `token_id` with the
+    /// default format, i.e. `{base_uri}/{token_id}`, without checking its
+    /// ownership. An empty base URI results in an empty URI.
     ///
     /// # Arguments
     ///
     /// * `e` - Access to the Soroban environment.
     /// * `base_uri` - The base URI.
     /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`compose_uri_with_format`] errors.
     pub fn compose_uri_for_token(e: &Env, base_uri: String, token_id: TokenId) -> String {
-        let len = base_uri.len() as usize;
+        Base::compose_uri_with_format(e, base_uri, token_id, &UriFormat::new(e))
+    }
 
-        if len > 0 {
-            // account for potentially the max num of digits of the TokenId type and a "/"
-            let uri = &mut [0u8; MAX_BASE_URI_LEN + MAX_NUM_DIGITS + 1];
+    /// Composes and returns the URI for a specific `token_id` as
+    /// `{base_uri}{separator}{token_id}{suffix}`, without checking its
+    /// ownership. The separator is skipped when `base_uri` already ends with
+    /// it. An empty base URI results in an empty URI.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `base_uri` - The base URI.
+    /// * `token_id` - The identifier of the token.
+    /// * `format` - The format of the URI.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::BaseUriMaxLenExceeded`] - If the length of
+    ///   `base_uri` exceeds the maximum allowed.
+    /// * [`NonFungibleTokenError::InvalidUriFormat`] - If `format` is not
+    ///   valid.
+    pub fn compose_uri_with_format(
+        e: &Env,
+        base_uri: String,
+        token_id: TokenId,
+        format: &UriFormat,
+    ) -> String {
+        let len = base_uri.len() as usize;
+        if len > MAX_BASE_URI_LEN {
+            panic_with_error!(e, NonFungibleTokenError::BaseUriMaxLenExceeded)
+        }
+        Base::validate_uri_format(e, format);
 
-            let (id, digits) = Base::token_id_to_string(e, token_id);
+        if len == 0 {
+            return String::from_str(e, "");
+        }
 
-            base_uri.copy_into_slice(&mut uri[..len]);
-            // check for '/' at the end of the base uri and add if needed
-            let mut offset = 0usize;
-            if uri[len - 1] != b'/' {
-                uri[len] = b'/';
-                offset += 1;
+        let uri = &mut [0u8; MAX_BASE_URI_LEN
+            + MAX_URI_SEPARATOR_LEN
+            + MAX_NUM_DIGITS
+            + MAX_URI_SUFFIX_LEN];
+        base_uri.copy_into_slice(&mut uri[..len]);
+        let mut end = len;
+
+        let separator_len = format.separator.len() as usize;
+        if separator_len > 0 {
+            let separator = &mut [0u8; MAX_URI_SEPARATOR_LEN];
+            format.separator.copy_into_slice(&mut separator[..separator_len]);
+            if len < separator_len || uri[(len - separator_len)..len] != separator[..separator_len]
+            {
+                uri[end..(end + separator_len)].copy_from_slice(&separator[..separator_len]);
+                end += separator_len;
             }
-            let end = len + digits + offset;
-            id.copy_into_slice(&mut uri[(len + offset)..end]);
-
-            String::from_bytes(e, &uri[..end])
-        } else {
-            String::from_str(e, "")
         }
+
+        end += Base::token_id_to_bytes(token_id, format, &mut uri[end..]);
+
+        let suffix_len = format.suffix.len() as usize;
+        format.suffix.copy_into_slice(&mut uri[end..(end + suffix_len)]);
+        end += suffix_len;
+
+        String::from_bytes(e, &uri[..end])
     }
 
     // ################## CHANGE STATE ##################
@@ -359,7 +475,8 @@ impl Base {
     /// * `owner` - The address granting approval for all their tokens.
     /// * `operator` - The address being granted or revoked approval.
     /// * `live_until_ledger` - The ledger number at which the allowance
-    ///   expires. If `live_until_ledger` is `0`, the approval is revoked.
+    ///   expires. If `live_until_ledger` is `0`, the approval is revoked (see
+    ///   [`Base::revoke_operator`]).
     ///
     /// # Errors
     ///
@@ -374,21 +491,13 @@ impl Base {
     /// # Notes
     ///
     /// * Authorization for `owner` is required.
+    /// * The expired operators of `owner` are pruned.
     pub fn approve_for_all(e: &Env, owner: &Address, operator: &Address, live_until_ledger: u32) {
         owner.require_auth();
 
-        let key = StorageKey::ApprovalForAll(owner.clone());
-
         // If revoking approval (live_until_ledger == 0)
         if live_until_ledger == 0 {
-            if let Some(mut approval_data) =
-                e.storage().temporary().get::<_, ApprovalForAllData>(&key)
-            {
-                approval_data.operators.remove(operator.clone());
-                e.storage().temporary().set(&key, &approval_data);
-                let live_for = live_until_ledger - e.ledger().sequence();
-                e.storage().temporary().extend_ttl(&key, live_for, live_for);
-            }
+            Base::remove_operator(e, owner, operator);
             emit_approve_for_all(e, owner, operator, live_until_ledger);
             return;
         }
@@ -399,29 +508,86 @@ impl Base {
             panic_with_error!(e, NonFungibleTokenError::InvalidLiveUntilLedger);
         }
 
-        // Retrieve or initialize the approval data
-        let mut approval_data = e
+        // Retrieve or initialize the operators
+        let mut operators = e
             .storage()
             .temporary()
-            .get::<_, ApprovalForAllData>(&key)
-            .unwrap_or_else(|| ApprovalForAllData { operators: Map::new(e) });
+            .get::<_, ApprovalForAllData>(&StorageKey::ApprovalForAll(owner.clone()))
+            .map(|approval_data| approval_data.operators)
+            .unwrap_or_else(|| Map::new(e));
 
         // Set the operator's expiration ledger
-        approval_data.operators.set(operator.clone(), live_until_ledger);
+        operators.set(operator.clone(), live_until_ledger);
+        Base::store_operators(e, owner, operators);
 
-        // Update the storage
-        e.storage().temporary().set(&key, &approval_data);
+        emit_approve_for_all(e, owner, operator, live_until_ledger);
+    }
 
-        // Update the TTL based on the expiration ledger
-        let live_for = live_until_ledger - e.ledger().sequence();
-        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    /// Revokes the approval of `token_id`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `approver` - The address revoking the approval (should be `owner` or
+    ///   `operator`).
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidApprover`] - If `approver` is
+    ///   neither the owner of the token nor one of its operators.
+    /// * refer to [`owner_of`] errors.
+    /// * refer to [`revoke_approval_for_owner`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address, token_id: TokenId]`
+    /// * data - `[approved: Address, live_until_ledger: u32]`
+    ///
+    /// The event is emitted with `live_until_ledger` set to `0`, and only if
+    /// the token had an approval.
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `approver` is required.
+    pub fn revoke_approval(e: &Env, approver: &Address, token_id: TokenId) {
+        approver.require_auth();
 
-        emit_approve_for_all(e, owner, operator, live_until_ledger);
+        let owner = Base::owner_of(e, token_id);
+        Base::revoke_approval_for_owner(e, &owner, approver, token_id);
+    }
+
+    /// Removes `operator` from the operators of `owner`. The expired
+    /// operators of `owner` are pruned as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The address holding the tokens.
+    /// * `operator` - The operator to remove.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["approve", owner: Address]`
+    /// * data - `[operator: Address, live_until_ledger: u32]`
+    ///
+    /// The event is emitted with `live_until_ledger` set to `0`.
+    ///
+    /// # Notes
+    ///
+    /// * Authorization for `owner` is required.
+    pub fn revoke_operator(e: &Env, owner: &Address, operator: &Address) {
+        owner.require_auth();
+
+        Base::remove_operator(e, owner, operator);
+        emit_approve_for_all(e, owner, operator, 0);
     }
 
     /// Low-level function for handling transfers, mints and burns of an NFT,
     /// without handling authorization. Updates ownership records, adjusts
-    /// balances, and clears existing approvals.
+    /// balances, and clears existing approvals and users (see the `rentable`
+    /// extension). On burn, the stored URI of the token (if any) is cleared as
+    /// well.
     ///
     /// # Arguments
     ///
@@ -451,6 +617,9 @@ impl Base {
             // Clear any existing approval
             let approval_key = StorageKey::Approval(token_id);
             e.storage().temporary().remove(&approval_key);
+
+            // Clear any existing user
+            Base::remove_user(e, token_id);
         } else {
             // nothing to do for the `None` case, since we don't track
             // `total_supply`
@@ -464,6 +633,7 @@ impl Base {
         } else {
             // Burning: `to` is None
             e.storage().persistent().remove(&StorageKey::Owner(token_id));
+            e.storage().persistent().remove(&uri_storage::StorageKey::TokenUri(token_id));
         }
     }
 
@@ -514,6 +684,38 @@ impl Base {
         emit_approve(e, approver, approved, token_id, live_until_ledger);
     }
 
+    /// Low-level function for revoking the approval of `token_id` without
+    /// checking its ownership and without handling authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `owner` - The owner of the token.
+    /// * `approver` - The address revoking the approval (should be `owner` or
+    ///   `operator`).
+    /// * `token_id` - The identifier of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidApprover`] - If `approver` is
+    ///   neither `owner` nor one of its operators.
+    pub fn revoke_approval_for_owner(
+        e: &Env,
+        owner: &Address,
+        approver: &Address,
+        token_id: TokenId,
+    ) {
+        if approver != owner && !Base::is_approved_for_all(e, owner, approver) {
+            panic_with_error!(e, NonFungibleTokenError::InvalidApprover);
+        }
+
+        let key = StorageKey::Approval(token_id);
+        if let Some(approval_data) = e.storage().temporary().get::<_, ApprovalData>(&key) {
+            e.storage().temporary().remove(&key);
+            emit_approve(e, approver, &approval_data.approved, token_id, 0);
+        }
+    }
+
     /// Low-level function for checking if the `spender` has enough approval
     /// prior a transfer, without checking ownership of `token_id` and
     /// without handling authorization.
@@ -598,6 +800,10 @@ impl Base {
     /// **IMPORTANT**: This function lacks authorization controls. You want to
     /// invoke it most likely from a constructor or from another function with
     /// admin-only authorization.
+    ///
+    /// This function does not emit any event. To change the base URI of a
+    /// collection that is already live, use [`Base::update_base_uri`]
+    /// instead, so that indexers and wallets learn about the change.
     pub fn set_metadata(e: &Env, base_uri: String, name: String, symbol: String) {
         if base_uri.len() as usize > MAX_BASE_URI_LEN {
             panic_with_error!(e, NonFungibleTokenError::BaseUriMaxLenExceeded)
@@ -607,34 +813,163 @@ impl Base {
         e.storage().instance().set(&StorageKey::Metadata, &metadata);
     }
 
+    /// Updates the collection base URI, keeping `name` and `symbol`, and
+    /// signals that the metadata of every token may have changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `base_uri` - The new base collection URI.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::BaseUriMaxLenExceeded`] - If the length of
+    ///   `base_uri` exceeds the maximum allowed.
+    /// * refer to [`get_metadata`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_metadata_update"]`
+    /// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+    ///
+    /// The event covers the whole `TokenId` range, i.e. `0` to
+    /// `TokenId::MAX`.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it from another function with admin-only authorization.
+    pub fn update_base_uri(e: &Env, base_uri: String) {
+        if base_uri.len() as usize > MAX_BASE_URI_LEN {
+            panic_with_error!(e, NonFungibleTokenError::BaseUriMaxLenExceeded)
+        }
+
+        let metadata = Metadata { base_uri, ..Base::get_metadata(e) };
+        e.storage().instance().set(&StorageKey::Metadata, &metadata);
+
+        emit_batch_metadata_update(e, 0, TokenId::MAX);
+    }
+
+    /// Updates the format used to compose the token URIs. By default, the
+    /// token URIs are composed as `{base_uri}/{token_id}` (see
+    /// [`UriFormat::new`]).
+    ///
+    /// The format is stored apart from [`Metadata`], so that the metadata
+    /// stored by a previous version of the contract can still be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `uri_format` - The new URI format.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidUriFormat`] - If the separator or the
+    ///   suffix exceed their maximum allowed length, or if the width exceeds
+    ///   the max. number of digits of `TokenId`.
+    /// * refer to [`get_metadata`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["batch_metadata_update"]`
+    /// * data - `[from_token_id: TokenId, to_token_id: TokenId]`
+    ///
+    /// The event covers the whole `TokenId` range, i.e. `0` to
+    /// `TokenId::MAX`.
+    ///
+    /// # Security Warning
+    ///
+    /// **IMPORTANT**: This function lacks authorization controls. You want to
+    /// invoke it from another function with admin-only authorization.
+    pub fn set_uri_format(e: &Env, uri_format: UriFormat) {
+        // the format is only meaningful along with a base URI
+        let _ = Base::get_metadata(e);
+        Base::validate_uri_format(e, &uri_format);
+
+        e.storage().instance().set(&StorageKey::UriFormat, &uri_format);
+
+        emit_batch_metadata_update(e, 0, TokenId::MAX);
+    }
+
     // ################## INTERNAL HELPERS ##################
 
-    /// Converts a numeric `TokenId` to `String` and returns it alongside the
-    /// number of digits.
-    fn token_id_to_string(e: &Env, value: TokenId) -> (String, usize) {
-        if value == 0 {
-            return (String::from_str(e, "0"), 0);
+    /// Removes `operator` from the operators of `owner`, if `owner` has any.
+    fn remove_operator(e: &Env, owner: &Address, operator: &Address) {
+        let key = StorageKey::ApprovalForAll(owner.clone());
+        if let Some(approval_data) = e.storage().temporary().get::<_, ApprovalForAllData>(&key) {
+            let mut operators = approval_data.operators;
+            operators.remove(operator.clone());
+            Base::store_operators(e, owner, operators);
         }
+    }
+
+    /// Stores the operators of `owner` without the expired ones, and extends
+    /// the TTL of the entry until the latest expiration ledger. The entry is
+    /// removed when no operator is left.
+    fn store_operators(e: &Env, owner: &Address, operators: Map<Address, u32>) {
+        let key = StorageKey::ApprovalForAll(owner.clone());
+        let current_ledger = e.ledger().sequence();
+
+        let mut live_operators = Map::new(e);
+        let mut live_until_ledger = current_ledger;
+        for (operator, expiry) in operators.iter() {
+            if expiry >= current_ledger {
+                live_operators.set(operator, expiry);
+                live_until_ledger = live_until_ledger.max(expiry);
+            }
+        }
+
+        if live_operators.is_empty() {
+            e.storage().temporary().remove(&key);
+            return;
+        }
+
+        e.storage().temporary().set(&key, &ApprovalForAllData { operators: live_operators });
+
+        let live_for = live_until_ledger - current_ledger;
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+
+    /// Checks the lengths of the separator and the suffix, and the padding
+    /// width of `format`.
+    fn validate_uri_format(e: &Env, format: &UriFormat) {
+        if format.separator.len() as usize > MAX_URI_SEPARATOR_LEN
+            || format.suffix.len() as usize > MAX_URI_SUFFIX_LEN
+            || format.width as usize > MAX_NUM_DIGITS
+        {
+            panic_with_error!(e, NonFungibleTokenError::InvalidUriFormat)
+        }
+    }
+
+    /// Writes `value` into `out` with the encoding and the padding width of
+    /// `format`, and returns the number of written bytes. `0` is written as
+    /// a single digit.
+    fn token_id_to_bytes(value: TokenId, format: &UriFormat, out: &mut [u8]) -> usize {
+        let radix: TokenId = match format.encoding {
+            IdEncoding::Decimal => 10,
+            IdEncoding::Hex => 16,
+        };
 
         let mut digits: usize = 0;
         let mut temp: TokenId = value;
-
-        while temp > 0 {
+        loop {
             digits += 1;
-            temp /= 10;
+            temp /= radix;
+            if temp == 0 {
+                break;
+            }
         }
 
-        let mut slice: [u8; MAX_NUM_DIGITS] = [0u8; MAX_NUM_DIGITS];
-        let mut index = digits;
+        // the remaining positions are filled with zeros once `temp` is `0`
+        let digits = digits.max(format.width as usize);
         temp = value;
-
-        while temp > 0 {
-            index -= 1;
-            slice[index] = (48 + temp % 10) as u8;
-            temp /= 10;
+        for byte in out[..digits].iter_mut().rev() {
+            let digit = (temp % radix) as u8;
+            *byte = if digit < 10 { b'0' + digit } else { b'a' + digit - 10 };
+            temp /= radix;
         }
 
-        (String::from_bytes(e, &slice[..digits]), digits)
+        digits
     }
 
     /// Creates a token with the next available `token_id` and assigns it to
@@ -647,6 +982,7 @@ impl Base {
     ///
     /// # Errors
     ///
+    /// * refer to [`record_mint`] errors.
     /// * refer to [`increment_token_id`] errors.
     /// * refer to [`update`] errors.
     ///
@@ -677,6 +1013,7 @@ impl Base {
     /// and generating `token_id`s, they should ensure that the token_id is
     /// unique and not already in use.
     pub fn sequential_mint(e: &Env, to: &Address) -> TokenId {
+        record_mint(e, 1);
         let token_id = increment_token_id(e, 1);
         Base::update(e, None, Some(to), token_id);
         emit_mint(e, to, token_id);
@@ -684,6 +1021,63 @@ impl Base {
         token_id
     }
 
+    /// Creates a token with the next available `token_id` for each of the
+    /// `recipients`, in the given order. Returns the first and the last
+    /// minted `token_id`s.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recipients` - The addresses receiving the new tokens, one token per
+    ///   entry.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidBatchSize`] - If `recipients` is
+    ///   empty or has more than [`MAX_BATCH_MINT_RECIPIENTS`] entries.
+    /// * refer to [`Base::sequential_mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// For each recipient:
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations (see [`Base::sequential_mint`]).
+    pub fn batch_mint_to(e: &Env, recipients: &Vec<Address>) -> (TokenId, TokenId) {
+        Base::check_batch_mint_recipients(e, recipients);
+
+        let first_id = next_token_id(e);
+        let mut last_id = first_id;
+        for to in recipients.iter() {
+            last_id = Base::sequential_mint(e, &to);
+        }
+        (first_id, last_id)
+    }
+
+    /// Panics if `recipients` can't be minted to in a single batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `recipients` - The addresses receiving the new tokens.
+    ///
+    /// # Errors
+    ///
+    /// * [`NonFungibleTokenError::InvalidBatchSize`] - If `recipients` is
+    ///   empty or has more than [`MAX_BATCH_MINT_RECIPIENTS`] entries.
+    pub fn check_batch_mint_recipients(e: &Env, recipients: &Vec<Address>) {
+        if recipients.is_empty() || recipients.len() > MAX_BATCH_MINT_RECIPIENTS {
+            panic_with_error!(e, NonFungibleTokenError::InvalidBatchSize);
+        }
+    }
+
     /// Creates a token with the provided `token_id` and assigns it to
     /// `to`.
     ///
@@ -695,6 +1089,7 @@ impl Base {
     ///
     /// # Errors
     ///
+    /// * refer to [`record_mint`] errors.
     /// * refer to [`update`] errors.
     ///
     /// # Events
@@ -725,7 +1120,45 @@ impl Base {
     /// generating `token_id`s varies by project and must be implemented
     /// accordingly.
     pub fn mint(e: &Env, to: &Address, token_id: TokenId) {
+        record_mint(e, 1);
         Base::update(e, None, Some(to), token_id);
         emit_mint(e, to, token_id);
     }
+
+    /// Creates a token with a random `token_id` drawn from the range set with
+    /// [`crate::random_ids::set_token_id_range`], and assigns it to `to`.
+    /// Returns the `token_id` for the newly minted token.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - Access to the Soroban environment.
+    /// * `to` - The address receiving the new token.
+    ///
+    /// # Errors
+    ///
+    /// * refer to [`next_random_token_id`] errors.
+    /// * refer to [`Base::mint`] errors.
+    ///
+    /// # Events
+    ///
+    /// * topics - `["mint", to: Address]`
+    /// * data - `[token_id: TokenId]`
+    ///
+    /// # Security Warning
+    ///
+    /// ⚠️ SECURITY RISK: This function has NO AUTHORIZATION CONTROLS ⚠️
+    ///
+    /// It is the responsibility of the implementer to establish appropriate
+    /// access controls to ensure that only authorized accounts can execute
+    /// minting operations.
+    ///
+    /// **IMPORTANT**: Do not mix random and sequential minting in the same
+    /// contract, unless the random range and the sequential ids do not
+    /// overlap.
+    pub fn random_mint(e: &Env, to: &Address) -> TokenId {
+        let token_id = next_random_token_id(e);
+        Base::mint(e, to, token_id);
+
+        token_id
+    }
 }
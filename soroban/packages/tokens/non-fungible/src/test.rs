@@ -4,12 +4,15 @@ extern crate std;
 
 use soroban_sdk::{
     contract,
-    testutils::{Address as _, Ledger as _},
-    Address, Env, Map, String,
+    testutils::{storage::Temporary as _, Address as _, Ledger as _},
+    vec, Address, Env, Map, String, Vec,
 };
 use stellar_event_assertion::EventAssertion;
 
-use crate::{non_fungible::Balance, ApprovalForAllData, Base, StorageKey};
+use crate::{
+    non_fungible::{Balance, MAX_BATCH_MINT_RECIPIENTS, MAX_NUM_DIGITS},
+    ApprovalForAllData, Base, IdEncoding, StorageKey, TokenId, UriFormat,
+};
 
 #[contract]
 struct MockContract;
@@ -41,6 +44,326 @@ fn metadata_works() {
     });
 }
 
+#[test]
+fn update_base_uri_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let collection_name = String::from_str(&e, "My NFT collection");
+        let collection_symbol = String::from_str(&e, "NFT");
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://hidden.com/"),
+            collection_name.clone(),
+            collection_symbol.clone(),
+        );
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        Base::update_base_uri(&e, String::from_str(&e, "https://revealed.com/"));
+
+        assert_eq!(Base::base_uri(&e), String::from_str(&e, "https://revealed.com/"));
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, "https://revealed.com/0"));
+        assert_eq!(collection_name, Base::name(&e));
+        assert_eq!(collection_symbol, Base::symbol(&e));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_non_fungible_mint(&owner, token_id);
+        event_assert.assert_batch_metadata_update(0, TokenId::MAX);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #310)")]
+fn update_base_uri_without_metadata_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::update_base_uri(&e, String::from_str(&e, "https://revealed.com/"));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #311)")]
+fn update_base_uri_too_long_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "https://hidden.com/"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+
+        Base::update_base_uri(&e, String::from_str(&e, &"a".repeat(201)));
+    });
+}
+
+fn uri_format(
+    e: &Env,
+    separator: &str,
+    suffix: &str,
+    encoding: IdEncoding,
+    width: u32,
+) -> UriFormat {
+    UriFormat {
+        separator: String::from_str(e, separator),
+        suffix: String::from_str(e, suffix),
+        encoding,
+        width,
+    }
+}
+
+#[test]
+fn compose_uri_for_token_zero_works() {
+    let e = Env::default();
+    let base_uri = String::from_str(&e, "https://smth.com");
+
+    let uri = Base::compose_uri_for_token(&e, base_uri.clone(), 0);
+    assert_eq!(uri, String::from_str(&e, "https://smth.com/0"));
+
+    let uri = Base::compose_uri_for_token(&e, String::from_str(&e, "https://smth.com/"), 0);
+    assert_eq!(uri, String::from_str(&e, "https://smth.com/0"));
+
+    let format = uri_format(&e, "/", "", IdEncoding::Hex, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri, 0, &format);
+    assert_eq!(uri, String::from_str(&e, "https://smth.com/0"));
+}
+
+#[test]
+fn compose_uri_for_token_empty_base_uri_works() {
+    let e = Env::default();
+    let format = uri_format(&e, "/", ".json", IdEncoding::Decimal, 4);
+
+    let uri = Base::compose_uri_with_format(&e, String::from_str(&e, ""), 7, &format);
+    assert_eq!(uri, String::from_str(&e, ""));
+}
+
+#[test]
+fn compose_uri_with_format_works() {
+    let e = Env::default();
+    let base_uri = String::from_str(&e, "ipfs://cid");
+
+    let format = uri_format(&e, "/", ".json", IdEncoding::Decimal, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri.clone(), 42, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/42.json"));
+
+    let format = uri_format(&e, "?id=", "", IdEncoding::Decimal, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri.clone(), 42, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid?id=42"));
+
+    // the separator is not repeated
+    let format = uri_format(&e, "?id=", "", IdEncoding::Decimal, 0);
+    let uri =
+        Base::compose_uri_with_format(&e, String::from_str(&e, "ipfs://cid?id="), 42, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid?id=42"));
+
+    let format = uri_format(&e, "", "", IdEncoding::Decimal, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri.clone(), 42, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid42"));
+
+    let format = uri_format(&e, "/", "", IdEncoding::Hex, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri.clone(), 0xbeef, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/beef"));
+
+    let format = uri_format(&e, "/", ".json", IdEncoding::Decimal, 5);
+    let uri = Base::compose_uri_with_format(&e, base_uri.clone(), 42, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/00042.json"));
+
+    // the width is a minimum
+    let format = uri_format(&e, "/", "", IdEncoding::Hex, 2);
+    let uri = Base::compose_uri_with_format(&e, base_uri, 0xbeef, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/beef"));
+}
+
+#[test]
+fn compose_uri_with_format_max_lengths_works() {
+    let e = Env::default();
+    let base_uri = "a".repeat(200);
+    let separator = "b".repeat(8);
+    let suffix = "c".repeat(16);
+    let format = uri_format(&e, &separator, &suffix, IdEncoding::Decimal, MAX_NUM_DIGITS as u32);
+
+    let uri =
+        Base::compose_uri_with_format(&e, String::from_str(&e, &base_uri), TokenId::MAX, &format);
+    let expected = std::format!("{base_uri}{separator}{}{suffix}", TokenId::MAX);
+    assert_eq!(uri, String::from_str(&e, &expected));
+}
+
+#[test]
+fn compose_uri_with_format_max_width_works() {
+    let e = Env::default();
+    let format = uri_format(&e, "/", "", IdEncoding::Decimal, MAX_NUM_DIGITS as u32);
+
+    let uri = Base::compose_uri_with_format(&e, String::from_str(&e, "ipfs://cid"), 0, &format);
+    let expected = std::format!("ipfs://cid/{}", "0".repeat(MAX_NUM_DIGITS));
+    assert_eq!(uri, String::from_str(&e, &expected));
+}
+
+#[cfg(feature = "token_u32")]
+#[test]
+fn compose_uri_for_token_max_u32_works() {
+    let e = Env::default();
+    let base_uri = String::from_str(&e, "ipfs://cid");
+
+    let uri = Base::compose_uri_for_token(&e, base_uri.clone(), TokenId::MAX);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/4294967295"));
+
+    let format = uri_format(&e, "/", "", IdEncoding::Hex, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri, TokenId::MAX, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/ffffffff"));
+}
+
+#[cfg(feature = "token_u64")]
+#[test]
+fn compose_uri_for_token_max_u64_works() {
+    let e = Env::default();
+    let base_uri = String::from_str(&e, "ipfs://cid");
+
+    let uri = Base::compose_uri_for_token(&e, base_uri.clone(), TokenId::MAX);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/18446744073709551615"));
+
+    let format = uri_format(&e, "/", "", IdEncoding::Hex, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri, TokenId::MAX, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/ffffffffffffffff"));
+}
+
+#[cfg(feature = "token_u128")]
+#[test]
+fn compose_uri_for_token_max_u128_works() {
+    let e = Env::default();
+    let base_uri = String::from_str(&e, "ipfs://cid");
+
+    let uri = Base::compose_uri_for_token(&e, base_uri.clone(), TokenId::MAX);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/340282366920938463463374607431768211455"));
+
+    let format = uri_format(&e, "/", "", IdEncoding::Hex, 0);
+    let uri = Base::compose_uri_with_format(&e, base_uri, TokenId::MAX, &format);
+    assert_eq!(uri, String::from_str(&e, "ipfs://cid/ffffffffffffffffffffffffffffffff"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #328)")]
+fn compose_uri_with_format_width_too_large_fails() {
+    let e = Env::default();
+    let format = uri_format(&e, "/", "", IdEncoding::Decimal, MAX_NUM_DIGITS as u32 + 1);
+
+    Base::compose_uri_with_format(&e, String::from_str(&e, "ipfs://cid"), 1, &format);
+}
+
+#[test]
+fn set_uri_format_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "ipfs://cid"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+        let token_id = Base::sequential_mint(&e, &owner);
+        assert_eq!(Base::uri_format(&e), UriFormat::new(&e));
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, "ipfs://cid/0"));
+
+        let format = uri_format(&e, "/", ".json", IdEncoding::Decimal, 3);
+        Base::set_uri_format(&e, format.clone());
+
+        assert_eq!(Base::uri_format(&e), format);
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, "ipfs://cid/000.json"));
+        assert_eq!(Base::base_uri(&e), String::from_str(&e, "ipfs://cid"));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(2);
+        event_assert.assert_non_fungible_mint(&owner, token_id);
+        event_assert.assert_batch_metadata_update(0, TokenId::MAX);
+    });
+}
+
+#[test]
+fn token_uri_without_stored_uri_format_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        // the metadata as stored before `UriFormat` was introduced
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "ipfs://cid"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+        let token_id = Base::sequential_mint(&e, &owner);
+
+        assert!(!e.storage().instance().has(&StorageKey::UriFormat));
+        assert_eq!(Base::uri_format(&e), UriFormat::new(&e));
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, "ipfs://cid/0"));
+
+        // updating the metadata keeps the stored format
+        let format = uri_format(&e, "/", ".json", IdEncoding::Decimal, 0);
+        Base::set_uri_format(&e, format.clone());
+        Base::update_base_uri(&e, String::from_str(&e, "ipfs://new"));
+
+        assert_eq!(Base::uri_format(&e), format);
+        assert_eq!(Base::token_uri(&e, token_id), String::from_str(&e, "ipfs://new/0.json"));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #328)")]
+fn set_uri_format_suffix_too_long_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "ipfs://cid"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+
+        Base::set_uri_format(&e, uri_format(&e, "/", &"c".repeat(17), IdEncoding::Decimal, 0));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #328)")]
+fn set_uri_format_separator_too_long_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_metadata(
+            &e,
+            String::from_str(&e, "ipfs://cid"),
+            String::from_str(&e, "My NFT collection"),
+            String::from_str(&e, "NFT"),
+        );
+
+        Base::set_uri_format(&e, uri_format(&e, &"b".repeat(9), "", IdEncoding::Decimal, 0));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #310)")]
+fn set_uri_format_without_metadata_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::set_uri_format(&e, UriFormat::new(&e));
+    });
+}
+
 #[test]
 fn approve_for_all_works() {
     let e = Env::default();
@@ -91,6 +414,233 @@ fn revoke_approve_for_all_works() {
     });
 }
 
+#[test]
+fn revoke_approve_for_all_after_ledger_zero_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(100);
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+        assert!(Base::is_approved_for_all(&e, &owner, &operator));
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 0);
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator));
+
+        // the entry is removed once the last operator is revoked
+        let key = StorageKey::ApprovalForAll(owner.clone());
+        assert!(!e.storage().temporary().has(&key));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_approve_for_all(&owner, &operator, 0);
+    });
+}
+
+#[test]
+fn revoke_approve_for_all_without_operators_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(100);
+        Base::approve_for_all(&e, &owner, &operator, 0);
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_approve_for_all(&owner, &operator, 0);
+    });
+}
+
+#[test]
+fn revoke_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator1 = Address::generate(&e);
+    let operator2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(100);
+        Base::approve_for_all(&e, &owner, &operator1, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator2, 2000);
+    });
+
+    e.as_contract(&address, || {
+        Base::revoke_operator(&e, &owner, &operator1);
+
+        assert!(!Base::is_approved_for_all(&e, &owner, &operator1));
+        assert!(Base::is_approved_for_all(&e, &owner, &operator2));
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_approve_for_all(&owner, &operator1, 0);
+    });
+}
+
+#[test]
+fn approve_for_all_prunes_expired_operators() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let expired = Address::generate(&e);
+    let live = Address::generate(&e);
+    let new = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &expired, 10);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &live, 1000);
+    });
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(20);
+        Base::approve_for_all(&e, &owner, &new, 500);
+
+        let key = StorageKey::ApprovalForAll(owner.clone());
+        let approval_data: ApprovalForAllData = e.storage().temporary().get(&key).unwrap();
+        assert_eq!(approval_data.operators.len(), 2);
+        assert!(!approval_data.operators.contains_key(expired.clone()));
+        assert_eq!(approval_data.operators.get(live.clone()), Some(1000));
+        assert_eq!(approval_data.operators.get(new.clone()), Some(500));
+
+        // the entry lives until the latest expiration ledger
+        assert_eq!(e.storage().temporary().get_ttl(&key), 980);
+    });
+}
+
+#[test]
+fn revoke_operator_prunes_expired_operators() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let expired = Address::generate(&e);
+    let operator = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &expired, 10);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        e.ledger().set_sequence_number(20);
+        Base::revoke_operator(&e, &owner, &operator);
+
+        let key = StorageKey::ApprovalForAll(owner.clone());
+        assert!(!e.storage().temporary().has(&key));
+    });
+}
+
+#[test]
+fn revoke_approval_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 1;
+
+    e.as_contract(&address, || {
+        e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+        Base::approve(&e, &owner, &approved, token_id, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::revoke_approval(&e, &owner, token_id);
+        assert_eq!(Base::get_approved(&e, token_id), None);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_approve(&owner, &approved, token_id, 0);
+    });
+}
+
+#[test]
+fn revoke_approval_with_operator_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 1;
+
+    e.as_contract(&address, || {
+        e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+        Base::approve(&e, &owner, &approved, token_id, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::approve_for_all(&e, &owner, &operator, 1000);
+    });
+
+    e.as_contract(&address, || {
+        Base::revoke_approval(&e, &operator, token_id);
+        assert_eq!(Base::get_approved(&e, token_id), None);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(1);
+        event_assert.assert_non_fungible_approve(&operator, &approved, token_id, 0);
+    });
+}
+
+#[test]
+fn revoke_approval_without_approval_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let token_id = 1;
+
+    e.as_contract(&address, || {
+        e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+
+        Base::revoke_approval(&e, &owner, token_id);
+        assert_eq!(Base::get_approved(&e, token_id), None);
+
+        let event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn revoke_approval_invalid_approver_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+    let approved = Address::generate(&e);
+    let token_id = 1;
+
+    e.as_contract(&address, || {
+        e.storage().persistent().set(&StorageKey::Owner(token_id), &owner);
+        Base::approve(&e, &owner, &approved, token_id, 1000);
+
+        Base::revoke_approval(&e, &approved, token_id);
+    });
+}
+
 #[test]
 fn approve_nft_works() {
     let e = Env::default();
@@ -444,6 +994,77 @@ fn test_counter_works() {
     });
 }
 
+#[test]
+fn batch_mint_to_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account1 = Address::generate(&e);
+    let account2 = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        Base::sequential_mint(&e, &account1);
+
+        let recipients = vec![&e, account1.clone(), account2.clone(), account1.clone()];
+        assert_eq!(Base::batch_mint_to(&e, &recipients), (1, 3));
+
+        assert_eq!(Base::balance(&e, &account1), 3);
+        assert_eq!(Base::balance(&e, &account2), 1);
+        assert_eq!(Base::owner_of(&e, 2), account2);
+        assert_eq!(Base::owner_of(&e, 3), account1);
+
+        let mut event_assert = EventAssertion::new(&e, address.clone());
+        event_assert.assert_event_count(4);
+        event_assert.assert_non_fungible_mint(&account1, 0);
+        event_assert.assert_non_fungible_mint(&account1, 1);
+        event_assert.assert_non_fungible_mint(&account2, 2);
+        event_assert.assert_non_fungible_mint(&account1, 3);
+    });
+}
+
+#[test]
+fn batch_mint_to_max_recipients_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let mut recipients = Vec::new(&e);
+        for _ in 0..MAX_BATCH_MINT_RECIPIENTS {
+            recipients.push_back(account.clone());
+        }
+        let last_id = TokenId::from(MAX_BATCH_MINT_RECIPIENTS) - 1;
+        assert_eq!(Base::batch_mint_to(&e, &recipients), (0, last_id));
+        assert_eq!(Base::balance(&e, &account), TokenId::from(MAX_BATCH_MINT_RECIPIENTS));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn batch_mint_to_too_many_recipients_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let account = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        let mut recipients = Vec::new(&e);
+        for _ in 0..=MAX_BATCH_MINT_RECIPIENTS {
+            recipients.push_back(account.clone());
+        }
+        Base::batch_mint_to(&e, &recipients);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")]
+fn batch_mint_to_no_recipients_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        Base::batch_mint_to(&e, &Vec::new(&e));
+    });
+}
+
 /// Test that confirms the base mint implementation does NOT require
 /// authorization
 ///
@@ -1 +1,2 @@
+pub mod random_ids;
 pub mod sequential;
@@ -0,0 +1,6 @@
+mod storage;
+mod test;
+
+pub use self::storage::{
+    next_random_token_id, remaining_token_ids, set_token_id_range, StorageKey, TokenIdRange,
+};
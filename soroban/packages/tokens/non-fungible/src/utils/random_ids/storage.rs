@@ -0,0 +1,160 @@
+use soroban_sdk::{contracttype, panic_with_error, Env};
+use stellar_constants::{TOKEN_EXTEND_AMOUNT, TOKEN_TTL_THRESHOLD};
+
+use crate::{NonFungibleTokenError, TokenId};
+
+/// Storage container for the range random `token_id`s are drawn from.
+#[contracttype]
+pub struct TokenIdRange {
+    /// The first `token_id` of the range.
+    pub first: TokenId,
+    /// The number of `token_id`s not handed out yet.
+    pub remaining: TokenId,
+}
+
+#[contracttype]
+pub enum StorageKey {
+    Range,
+    /// The offset (relative to `first`) stored at a position of the pool.
+    /// Positions without an entry hold their own value.
+    Slot(TokenId),
+}
+
+/// Returns the number of `token_id`s that can still be handed out.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+pub fn remaining_token_ids(e: &Env) -> TokenId {
+    e.storage()
+        .instance()
+        .get::<_, TokenIdRange>(&StorageKey::Range)
+        .map(|range| range.remaining)
+        .unwrap_or(0)
+}
+
+/// Sets the range `[first, first + count)` the random `token_id`s are drawn
+/// from.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+/// * `first` - The first `token_id` of the range.
+/// * `count` - The number of `token_id`s in the range.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::InvalidTokenIdRange`] - When the range is
+///   already set.
+/// * [`NonFungibleTokenError::MathOverflow`] - When the range exceeds
+///   `TokenId::MAX`.
+///
+/// # Notes
+///
+/// We recommend using this function in the constructor of your smart
+/// contract. The range cannot be changed once it is set.
+pub fn set_token_id_range(e: &Env, first: TokenId, count: TokenId) {
+    if e.storage().instance().has(&StorageKey::Range) {
+        panic_with_error!(e, NonFungibleTokenError::InvalidTokenIdRange);
+    }
+    if first.checked_add(count).is_none() {
+        panic_with_error!(e, NonFungibleTokenError::MathOverflow);
+    }
+    e.storage().instance().set(&StorageKey::Range, &TokenIdRange { first, remaining: count });
+}
+
+/// Draws a random `token_id` from the range, without replacement, and returns
+/// it.
+///
+/// The ids are handed out with a Fisher–Yates shuffle performed one step at a
+/// time: a random position of the pool of remaining ids is picked, and the
+/// last position of the pool is moved into its place. The pool is only
+/// populated lazily, so the cost of a draw does not depend on the size of the
+/// range.
+///
+/// # Arguments
+///
+/// * `e` - Access to the Soroban environment.
+///
+/// # Errors
+///
+/// * [`NonFungibleTokenError::TokenIDsAreDepleted`] - When all the
+///   `token_id`s of the range are handed out, or no range is set.
+///
+/// # Security Warning
+///
+/// The randomness comes from `e.prng()`, which is seeded by the network. It
+/// prevents minters from predicting their `token_id`, but validators could
+/// in theory influence it.
+pub fn next_random_token_id(e: &Env) -> TokenId {
+    let Some(mut range) = e.storage().instance().get::<_, TokenIdRange>(&StorageKey::Range) else {
+        panic_with_error!(e, NonFungibleTokenError::TokenIDsAreDepleted);
+    };
+    if range.remaining == 0 {
+        panic_with_error!(e, NonFungibleTokenError::TokenIDsAreDepleted);
+    }
+
+    let last = range.remaining - 1;
+    let position = random_position(e, last);
+
+    let picked = read_slot(e, position);
+    if position != last {
+        let moved = read_slot(e, last);
+        let key = StorageKey::Slot(position);
+        e.storage().persistent().set(&key, &moved);
+    }
+    e.storage().persistent().remove(&StorageKey::Slot(last));
+
+    range.remaining = last;
+    e.storage().instance().set(&StorageKey::Range, &range);
+
+    // NOTE: can't overflow, as the whole range was checked on creation.
+    range.first + picked
+}
+
+/// Returns a random position of the pool, i.e. in `0..=last`.
+#[cfg(feature = "token_u32")]
+fn random_position(e: &Env, last: TokenId) -> TokenId {
+    // NOTE: can't truncate, as the position does not exceed `last`.
+    e.prng().gen_range::<u64>(0..=u64::from(last)) as TokenId
+}
+
+/// Returns a random position of the pool, i.e. in `0..=last`.
+#[cfg(feature = "token_u64")]
+fn random_position(e: &Env, last: TokenId) -> TokenId {
+    e.prng().gen_range::<u64>(0..=last)
+}
+
+/// Returns a random position of the pool, i.e. in `0..=last`.
+///
+/// `Prng::gen_range` only supports `u64`, so positions beyond `u64::MAX` are
+/// drawn by rejection sampling: random bits are drawn over the smallest power
+/// of two covering `last` until the value is in range, which happens for more
+/// than half of the draws.
+#[cfg(feature = "token_u128")]
+fn random_position(e: &Env, last: TokenId) -> TokenId {
+    if let Ok(last) = u64::try_from(last) {
+        return e.prng().gen_range::<u64>(0..=last).into();
+    }
+
+    let mask = TokenId::MAX >> last.leading_zeros();
+    loop {
+        let high = TokenId::from(e.prng().gen::<u64>());
+        let low = TokenId::from(e.prng().gen::<u64>());
+        let value = ((high << 64) | low) & mask;
+        if value <= last {
+            return value;
+        }
+    }
+}
+
+/// Returns the value stored at `position` of the pool.
+fn read_slot(e: &Env, position: TokenId) -> TokenId {
+    let key = StorageKey::Slot(position);
+    if let Some(value) = e.storage().persistent().get::<_, TokenId>(&key) {
+        e.storage().persistent().extend_ttl(&key, TOKEN_TTL_THRESHOLD, TOKEN_EXTEND_AMOUNT);
+        value
+    } else {
+        position
+    }
+}
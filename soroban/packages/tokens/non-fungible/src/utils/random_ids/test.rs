@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+extern crate std;
+
+use std::collections::HashSet;
+
+use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+use crate::{
+    enumerable::Enumerable,
+    random_ids::{next_random_token_id, remaining_token_ids, set_token_id_range},
+    Base, TokenId,
+};
+
+#[contract]
+pub struct MockContract;
+
+#[test]
+fn random_token_ids_are_unique_and_in_range() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, 100, 50);
+        assert_eq!(remaining_token_ids(&e), 50);
+
+        let mut seen = HashSet::new();
+        for i in 0..50 {
+            let token_id = next_random_token_id(&e);
+            assert!((100..150).contains(&token_id));
+            assert!(seen.insert(token_id));
+            assert_eq!(remaining_token_ids(&e), 49 - i);
+        }
+    });
+}
+
+#[test]
+fn random_token_ids_are_not_sequential() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, 0, 1000);
+
+        let ids: std::vec::Vec<TokenId> = (0..10).map(|_| next_random_token_id(&e)).collect();
+        assert_ne!(ids, (0..10).collect::<std::vec::Vec<TokenId>>());
+    });
+}
+
+#[test]
+fn random_token_ids_span_the_whole_token_id_width() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, 0, TokenId::MAX);
+
+        let ids: HashSet<TokenId> = (0..10).map(|_| next_random_token_id(&e)).collect();
+        assert_eq!(ids.len(), 10);
+        // draws over the whole range, e.g. not truncated to `u64` for `u128`
+        assert!(ids.iter().any(|&id| id > TokenId::MAX / 2));
+        assert_eq!(remaining_token_ids(&e), TokenId::MAX - 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #306)")]
+fn random_token_ids_depleted_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, 0, 2);
+        next_random_token_id(&e);
+        next_random_token_id(&e);
+        next_random_token_id(&e);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #306)")]
+fn random_token_id_without_range_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        next_random_token_id(&e);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #327)")]
+fn set_token_id_range_twice_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, 0, 10);
+        set_token_id_range(&e, 10, 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #305)")]
+fn set_token_id_range_overflow_fails() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, TokenId::MAX, 2);
+    });
+}
+
+#[test]
+fn random_mint_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, 1, 10);
+
+        let token_id = Base::random_mint(&e, &owner);
+
+        assert!((1..11).contains(&token_id));
+        assert_eq!(Base::owner_of(&e, token_id), owner);
+        assert_eq!(remaining_token_ids(&e), 9);
+    });
+}
+
+#[test]
+fn enumerable_random_mint_works() {
+    let e = Env::default();
+    let address = e.register(MockContract, ());
+    let owner = Address::generate(&e);
+
+    e.as_contract(&address, || {
+        set_token_id_range(&e, 0, 10);
+
+        let token_id1 = Enumerable::random_mint(&e, &owner);
+        let token_id2 = Enumerable::random_mint(&e, &owner);
+
+        assert_eq!(Enumerable::total_supply(&e), 2);
+        assert_eq!(Enumerable::get_token_id(&e, 0), token_id1);
+        assert_eq!(Enumerable::get_token_id(&e, 1), token_id2);
+        assert_eq!(Enumerable::get_owner_token_id(&e, &owner, 1), token_id2);
+    });
+}